@@ -0,0 +1,96 @@
+use itertools::Itertools;
+
+use crate::aspect;
+
+/// The mirror images of a point across the solstice and equinox axes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Reflection {
+    /// Reflected across 0° Cancer – 0° Capricorn
+    Antiscion,
+    /// Reflected across 0° Aries – 0° Libra
+    ContraAntiscion,
+}
+
+impl Reflection {
+    pub const ALL: [Reflection; 2] = [Reflection::Antiscion, Reflection::ContraAntiscion];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Reflection::Antiscion => "Antiscion",
+            Reflection::ContraAntiscion => "Contra-antiscion",
+        }
+    }
+
+    pub fn reflect(self, position: f32) -> f32 {
+        match self {
+            Reflection::Antiscion => (180. - position).rem_euclid(360.),
+            Reflection::ContraAntiscion => (360. - position) % 360.,
+        }
+    }
+}
+
+/// A body conjunct the antiscion or contra-antiscion of another
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contact {
+    pub first: usize,
+    pub second: usize,
+    pub reflection: Reflection,
+    /// 1 for an exact contact, falling to 0 at the edge of the orb
+    pub close: f32,
+    pub orb: f32,
+}
+
+/// The contacts between `points` and the reflections of the others, using the orb of
+/// conjunctions. The relation is symmetric, so each pair is only reported once.
+pub fn contacts(points: &[f32], orbis: f32) -> Vec<Contact> {
+    points
+        .iter()
+        .enumerate()
+        .tuple_combinations()
+        .flat_map(|((first, &a), (second, &b))| {
+            Reflection::ALL.iter().filter_map(move |&reflection| {
+                match aspect::aspect(a, reflection.reflect(b), orbis) {
+                    Some(asp) if asp.aspect_type == aspect::Type::Zero => Some(Contact {
+                        first,
+                        second,
+                        reflection,
+                        close: asp.close,
+                        orb: asp.orb,
+                    }),
+                    _ => None,
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reflects_across_the_solstices_and_equinoxes() {
+        assert!((Reflection::Antiscion.reflect(10.) - 170.).abs() < 1e-4);
+        assert!((Reflection::Antiscion.reflect(200.) - 340.).abs() < 1e-4);
+        assert!((Reflection::ContraAntiscion.reflect(10.) - 350.).abs() < 1e-4);
+        assert!(Reflection::ContraAntiscion.reflect(0.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn finds_each_contact_once() {
+        let found = contacts(&[10., 172., 352., 90.], 5.);
+        let pairs: Vec<(usize, usize, Reflection)> = found
+            .iter()
+            .map(|contact| (contact.first, contact.second, contact.reflection))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (0, 1, Reflection::Antiscion),
+                (0, 2, Reflection::ContraAntiscion),
+            ]
+        );
+        assert!((found[0].orb - 2.).abs() < 1e-4);
+        assert!((found[1].close - 0.6).abs() < 1e-4);
+    }
+}
@@ -1,11 +1,76 @@
 use std::str::FromStr;
 use yew::prelude::*;
 
-use crate::components::{Drawing, ListView, TextInput};
+use crate::components::{DignityTable, Drawing, ListView, LotTable, MidpointView, TextInput};
+use crate::date::DateTime;
+use crate::lots::{self, Lot};
 
 pub struct App {
     link: ComponentLink<Self>,
-    positions: Positions,
+    chart: Chart,
+    lots: Vec<Lot>,
+}
+
+/// The positions of a chart along with what is known about the moment it was cast for
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Chart {
+    pub positions: Positions,
+    /// The daily motion in degrees of the bodies from the Sun to the Node, where the input
+    /// gives it. It is negative for the bodies that are moving backwards.
+    pub speeds: [Option<f32>; 11],
+    pub birth: Option<DateTime>,
+}
+
+/// The names of the points, in the order they are stored in `Positions`
+pub const NAMES: [&str; 13] = [
+    "Sun",
+    "Moon",
+    "Mercury",
+    "Venus",
+    "Mars",
+    "Jupiter",
+    "Saturn",
+    "Uranus",
+    "Neptune",
+    "Pluto",
+    "Node",
+    "MC",
+    "Ascendant",
+];
+
+/// Short symbols for the points, in the order they are stored in `Positions`
+pub const GLYPHS: [&str; 13] = [
+    "\u{2609}", "\u{263d}", "\u{263f}", "\u{2640}", "\u{2642}", "\u{2643}", "\u{2644}", "\u{2645}",
+    "\u{2646}", "\u{2647}", "\u{260a}", "MC", "ASC",
+];
+
+lazy_static! {
+    static ref ZODIAC_SIGNS: Vec<&'static str> =
+        vec!["Ari", "Tau", "Gem", "Can", "Leo", "Vir", "Lib", "Sco", "Sag", "Cap", "Aqu", "Psc",];
+}
+
+/// A longitude split into its sign, counted from Aries, and the whole degrees and minutes
+/// within the sign. The minutes are rounded, carrying into the next degree and sign.
+#[must_use]
+pub fn sign_degrees_minutes(angle: f32) -> (usize, u32, u32) {
+    let minutes = (angle.rem_euclid(360.) * 60.).round() as u32 % (360 * 60);
+    (
+        minutes as usize / (30 * 60),
+        minutes % (30 * 60) / 60,
+        minutes % 60,
+    )
+}
+
+/// A longitude as degrees and minutes in its sign, like `05° 21' Tau`
+///
+/// # Panics
+///
+/// If the longitude is negative
+#[must_use]
+pub fn truncate_angle(angle: f32) -> String {
+    assert!(angle >= 0.);
+    let (sign, degrees, minutes) = sign_degrees_minutes(angle);
+    format!("{:02}° {:02}' {}", degrees, minutes, ZODIAC_SIGNS[sign])
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -19,31 +84,37 @@ impl Default for Positions {
 
 impl Positions {
     /// The position of the sun
+    #[must_use]
     pub fn sun(&self) -> f32 {
         self.0[0]
     }
 
     /// The position of the moon
+    #[must_use]
     pub fn moon(&self) -> f32 {
         self.0[1]
     }
 
     /// The position of the descendant
+    #[must_use]
     pub fn descendant(&self) -> f32 {
         self.0[11]
     }
 
     /// The position of the ascendant
+    #[must_use]
     pub fn ascendant(&self) -> f32 {
         self.0[12]
     }
 
     /// The position of the eleven planets, from the Sun up to and including the Node
+    #[must_use]
     pub fn planets(&self) -> &[f32] {
         &self.0[0..11]
     }
 
     /// The position of the ten planets, including Pluto but not Node
+    #[must_use]
     pub fn planets_without_node(&self) -> &[f32] {
         &self.0[0..10]
     }
@@ -52,7 +123,8 @@ impl Positions {
 pub enum Msg {
     #[allow(unused)]
     Noop,
-    NewPositions(Positions),
+    NewChart(Chart),
+    LotsChange(Vec<Lot>),
 }
 
 impl Component for App {
@@ -62,7 +134,8 @@ impl Component for App {
     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
         App {
             link,
-            positions: Positions::default(),
+            chart: Chart::default(),
+            lots: lots::standard(),
         }
     }
 
@@ -73,40 +146,75 @@ impl Component for App {
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::Noop => return false,
-            Msg::NewPositions(positions) => self.positions = positions,
+            Msg::NewChart(chart) => self.chart = chart,
+            Msg::LotsChange(lots) => self.lots = lots,
         }
         true
     }
 
     fn view(&self) -> Html {
-        let on_positions_change = self.link.callback(Msg::NewPositions);
+        let on_chart_change = self.link.callback(Msg::NewChart);
+        let lot_positions = lots::positions(&self.lots, &self.chart.positions);
 
         html! {
             <div class="container">
                 <div class="row">
-                    <Drawing positions=&self.positions />
+                    <Drawing
+                        positions=&self.chart.positions
+                        speeds=self.chart.speeds
+                        birth=self.chart.birth
+                        lots=&lot_positions
+                    />
                     <div class="col">
                         <h4>{ "Numeric positions" }</h4>
-                        <ListView positions=&self.positions />
+                        <ListView positions=&self.chart.positions lots=lot_positions />
                     </div>
                 </div>
-                <TextInput on_change=on_positions_change />
+                <TextInput on_change=on_chart_change />
+                <h4>{ "Lots" }</h4>
+                <LotTable
+                    positions=&self.chart.positions
+                    lots=&self.lots
+                    on_change=self.link.callback(Msg::LotsChange)
+                />
+                <h4>{ "Essential dignities" }</h4>
+                <DignityTable positions=&self.chart.positions />
+                <h4>{ "Midpoints" }</h4>
+                <MidpointView positions=&self.chart.positions />
             </div>
         }
     }
 }
 
+/// Parse the value of a form control
+///
+/// # Errors
+///
+/// Fails if the value does not parse as a `T`
 pub fn try_from_change_data<T: FromStr>(cd: ChangeData) -> Result<T, T::Err> {
     match cd {
         ChangeData::Value(val) => val.parse(),
-        _ => unreachable!(),
+        ChangeData::Select(elem) => elem.value().parse(),
+        ChangeData::Files(_) => unreachable!(),
     }
 }
 
-pub fn harmonics(positions: &Positions, harmonic: u16) -> Positions {
+/// Whether a harmonic chart multiplies the MC and ascendant along with the bodies
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Angles {
+    Harmonised,
+    /// Keep the radix MC and ascendant, so that the horizon stays put between harmonics
+    Fixed,
+}
+
+#[must_use]
+pub fn harmonics(positions: &Positions, harmonic: f32, angles: Angles) -> Positions {
     let mut new_positions: [f32; 13] = [0.; 13];
     for (i, pos) in positions.0.iter().enumerate() {
-        new_positions[i] = (pos * f32::from(harmonic)) % 360.
+        new_positions[i] = (pos * harmonic) % 360.
+    }
+    if angles == Angles::Fixed {
+        new_positions[11..].copy_from_slice(&positions.0[11..]);
     }
     Positions(new_positions)
 }
@@ -1,8 +1,12 @@
 pub struct Aspect {
     pub aspect_type: Type,
+    /// 1 for an exact aspect, falling to 0 at the edge of the orb
     pub close: f32,
+    /// The distance from the exact aspect in degrees
+    pub orb: f32,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Type {
     Zero,
     Thirty,
@@ -12,34 +16,125 @@ pub enum Type {
     OneEighty,
 }
 
+impl Type {
+    /// All aspect types, ordered by angle
+    pub const ALL: [Type; 6] = [
+        Type::Zero,
+        Type::Thirty,
+        Type::Sixty,
+        Type::Ninety,
+        Type::OneTwenty,
+        Type::OneEighty,
+    ];
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Type::Zero => "Conjunction",
+            Type::Thirty => "Semi-sextile",
+            Type::Sixty => "Sextile",
+            Type::Ninety => "Square",
+            Type::OneTwenty => "Trine",
+            Type::OneEighty => "Opposition",
+        }
+    }
+}
+
+/// The set of aspect types that should be drawn
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Selection([bool; 6]);
+
+impl Default for Selection {
+    /// Everything except the semi-sextile
+    fn default() -> Self {
+        Self([true, false, true, true, true, true])
+    }
+}
+
+impl Selection {
+    #[must_use]
+    pub fn contains(self, aspect_type: Type) -> bool {
+        self.0[aspect_type as usize]
+    }
+
+    pub fn toggle(&mut self, aspect_type: Type) {
+        self.0[aspect_type as usize] = !self.0[aspect_type as usize];
+    }
+}
+
 impl Aspect {
-    fn new(aspect_type: Type, close: f32) -> Aspect {
-        Self { aspect_type, close }
+    fn new(aspect_type: Type, close: f32, orb: f32) -> Aspect {
+        Self {
+            aspect_type,
+            close,
+            orb,
+        }
     }
 }
 
+#[must_use]
 pub fn aspect(a: f32, b: f32, orbis: f32) -> Option<Aspect> {
     let distance = (a - b).abs();
     let distance = distance.min(360. - distance);
-    if distance < orbis {
-        let close = distance / orbis;
-        Some(Aspect::new(Type::Zero, close))
-    } else if (distance - 30.).abs() < 0.25 * orbis {
-        let close = 1. - (30. - distance).abs() / (orbis * 0.25);
-        Some(Aspect::new(Type::Thirty, close))
-    } else if (distance - 60.).abs() < 0.75 * orbis {
-        let close = 1. - (60. - distance).abs() / (orbis * 0.75);
-        Some(Aspect::new(Type::Sixty, close))
-    } else if (distance - 90.).abs() < orbis {
-        let close = 1. - (90. - distance).abs() / orbis;
-        Some(Aspect::new(Type::Ninety, close))
-    } else if (distance - 120.).abs() < orbis {
-        let close = 1. - (120. - distance).abs() / orbis;
-        Some(Aspect::new(Type::OneTwenty, close))
-    } else if (distance - 180.).abs() < orbis {
-        let close = 1. - (180. - distance).abs() / orbis;
-        Some(Aspect::new(Type::OneEighty, close))
-    } else {
-        None
+    let within = |exact: f32, orbis: f32, aspect_type: Type| {
+        let deviation = (distance - exact).abs();
+        if deviation < orbis {
+            Some(Aspect::new(aspect_type, 1. - deviation / orbis, deviation))
+        } else {
+            None
+        }
+    };
+    within(0., orbis, Type::Zero)
+        .or_else(|| within(30., 0.25 * orbis, Type::Thirty))
+        .or_else(|| within(60., 0.75 * orbis, Type::Sixty))
+        .or_else(|| within(90., orbis, Type::Ninety))
+        .or_else(|| within(120., orbis, Type::OneTwenty))
+        .or_else(|| within(180., orbis, Type::OneEighty))
+}
+
+/// The widest orb used in a harmonic chart. Beyond this the orbs of neighbouring aspects
+/// overlap and everything becomes a conjunction.
+const MAX_ORB: f32 = 15.;
+
+/// Which chart the configured orb is measured in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrbBasis {
+    /// The orb applies as it is in the harmonic chart, which makes it `1 / H` as wide in the
+    /// radix
+    HarmonicChart,
+    /// The orb is a radix orb, which is `H` times as wide in the harmonic chart
+    Radix,
+}
+
+/// The orb for the main aspects, from which the orbs of the others are derived
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Orb {
+    pub degrees: f32,
+    pub basis: OrbBasis,
+}
+
+impl Default for Orb {
+    fn default() -> Self {
+        Self {
+            degrees: 8.,
+            basis: OrbBasis::HarmonicChart,
+        }
+    }
+}
+
+impl Orb {
+    /// The orb to use in a chart of the given harmonic
+    #[must_use]
+    pub fn in_harmonic_chart(self, harmonic: f32) -> f32 {
+        match self.basis {
+            OrbBasis::HarmonicChart => self.degrees,
+            OrbBasis::Radix => (self.degrees * harmonic).min(MAX_ORB),
+        }
+    }
+
+    /// The radix equivalent of the orb used in a chart of the given harmonic
+    #[must_use]
+    pub fn in_radix(self, harmonic: f32) -> f32 {
+        self.in_harmonic_chart(harmonic) / harmonic
     }
 }
@@ -0,0 +1,218 @@
+use std::time::Duration;
+use yew::prelude::*;
+use yew::services::interval::{IntervalService, IntervalTask};
+
+use crate::app::try_from_change_data;
+use crate::date::{self, DateTime};
+use crate::harmonic;
+
+const TICK: Duration = Duration::from_millis(250);
+
+/// Draws the Addey age harmonic, age + 1, for a chosen date
+pub struct AgeHarmonic {
+    link: ComponentLink<Self>,
+    props: Props,
+    target: Option<DateTime>,
+    text: String,
+    unit: StepUnit,
+    error: Option<String>,
+    playing: Option<IntervalTask>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub birth: Option<DateTime>,
+    pub on_change: Callback<f32>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum StepUnit {
+    Day,
+    Month,
+    Year,
+}
+
+impl StepUnit {
+    fn name(self) -> &'static str {
+        match self {
+            StepUnit::Day => "Day",
+            StepUnit::Month => "Month",
+            StepUnit::Year => "Year",
+        }
+    }
+}
+
+pub enum Msg {
+    TargetChange(String),
+    UnitChange(StepUnit),
+    Step(i32),
+    TogglePlay,
+}
+
+impl Component for AgeHarmonic {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            target: None,
+            text: String::new(),
+            unit: StepUnit::Month,
+            error: None,
+            playing: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::TargetChange(text) => {
+                match text.parse() {
+                    Ok(target) => self.set_target(target),
+                    Err(err) => self.error = Some(err),
+                }
+                self.text = text;
+            }
+            Msg::UnitChange(unit) => self.unit = unit,
+            Msg::Step(steps) => {
+                if let Some(start) = self.target.or(self.props.birth) {
+                    let target = match self.unit {
+                        StepUnit::Day => start.add_days(steps),
+                        StepUnit::Month => start.add_months(steps),
+                        StepUnit::Year => start.add_years(steps),
+                    };
+                    match target {
+                        Ok(target) => self.set_target(target),
+                        Err(err) => {
+                            // Stepping on would only fail again
+                            self.playing = None;
+                            self.error = Some(err);
+                        }
+                    }
+                }
+            }
+            Msg::TogglePlay => {
+                self.playing = match self.playing {
+                    Some(_) => None,
+                    None => Some(
+                        IntervalService::new().spawn(TICK, self.link.callback(|()| Msg::Step(1))),
+                    ),
+                }
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let birth_changed = self.props.birth != props.birth;
+            self.props = props;
+            if self.props.birth.is_none() {
+                // There is nothing to play through without a birth date
+                self.playing = None;
+            }
+            if let (true, Some(target)) = (birth_changed, self.target) {
+                self.set_target(target);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let birth = match self.props.birth {
+            Some(birth) => birth,
+            None => {
+                return html! {
+                    <p>{ "Enter a birth date with the chart to draw age harmonics." }</p>
+                }
+            }
+        };
+        let on_target_change = self.link.callback(|cd| {
+            // Text inputs always produce a value, which always parses as a string
+            Msg::TargetChange(try_from_change_data(cd).unwrap())
+        });
+        let unit_button = |unit: StepUnit| {
+            let class = if unit == self.unit {
+                "btn btn-secondary active"
+            } else {
+                "btn btn-secondary"
+            };
+            html! {
+                <button class=class onclick=self.link.callback(move |_| Msg::UnitChange(unit))>
+                    { unit.name() }
+                </button>
+            }
+        };
+        let readout = match (&self.error, self.target) {
+            (Some(err), _) => html! { <div class="alert alert-warning">{ err }</div> },
+            (None, Some(target)) => {
+                let age = date::age(birth, target);
+                html! {
+                    <p>{ format!(
+                        "Age {:.3}, harmonic {}",
+                        age,
+                        harmonic::describe(age as f32 + 1.)
+                    ) }</p>
+                }
+            }
+            (None, None) => html! {},
+        };
+        html! {
+            <div class="form-group">
+                <label for="age-target">{ "Age harmonic at date" }</label>
+                <input
+                    id="age-target"
+                    class="form-control text-monospace"
+                    type="text"
+                    placeholder=birth.to_string()
+                    value=self.text
+                    onchange=on_target_change
+                />
+                <div class="btn-toolbar" role="toolbar">
+                    <div class="btn-group btn-group-sm mr-2" role="group">
+                        { unit_button(StepUnit::Day) }
+                        { unit_button(StepUnit::Month) }
+                        { unit_button(StepUnit::Year) }
+                    </div>
+                    <div class="btn-group btn-group-sm" role="group">
+                        <button class="btn btn-secondary" onclick=self.link.callback(|_| Msg::Step(-1))>
+                            { '◀' }
+                        </button>
+                        <button class="btn btn-secondary" onclick=self.link.callback(|_| Msg::TogglePlay)>
+                            { if self.playing.is_some() { "Pause" } else { "Play" } }
+                        </button>
+                        <button class="btn btn-secondary" onclick=self.link.callback(|_| Msg::Step(1))>
+                            { '▶' }
+                        </button>
+                    </div>
+                </div>
+                { readout }
+            </div>
+        }
+    }
+}
+
+impl AgeHarmonic {
+    fn set_target(&mut self, target: DateTime) {
+        self.target = Some(target);
+        self.text = target.to_string();
+        let birth = match self.props.birth {
+            Some(birth) => birth,
+            None => return,
+        };
+        let harmonic = date::age(birth, target) as f32 + 1.;
+        if harmonic < 1. {
+            self.error = Some(format!("{} is before the birth", target));
+            self.playing = None;
+        } else if harmonic <= harmonic::MAX_HARMONIC {
+            self.error = None;
+            self.props.on_change.emit(harmonic);
+        } else {
+            self.error = Some(format!("There is no age harmonic for {}", target));
+            self.playing = None;
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use itertools::Itertools;
+use yew::prelude::*;
+
+use crate::antiscia;
+use crate::app::{Positions, NAMES};
+use crate::aspect::{self, Orb};
+use crate::glyphs::{self, GlyphStyle};
+
+pub struct AspectList {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// The positions in the drawn chart, which may be a harmonic chart
+    pub positions: Positions,
+    pub harmonic: f32,
+    pub planets: u16,
+    pub aspect_types: aspect::Selection,
+    pub orb: Orb,
+    /// Also list the contacts between bodies and the antiscia of others
+    pub antiscia: bool,
+    pub glyph_style: GlyphStyle,
+}
+
+impl Component for AspectList {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let harmonic = self.props.harmonic;
+        let orbis = self.props.orb.in_harmonic_chart(harmonic);
+        let bodies = &self.props.positions.planets_without_node()
+            [..(self.props.planets as usize + 2).min(10)];
+        let rows = bodies
+            .iter()
+            .enumerate()
+            .tuple_combinations()
+            .filter_map(|((i, &a), (j, &b))| match aspect::aspect(a, b, orbis) {
+                Some(asp) if self.props.aspect_types.contains(asp.aspect_type) => Some((i, j, asp)),
+                _ => None,
+            })
+            .map(|(i, j, asp)| {
+                html! {
+                    <tr>
+                        <td>{ format!("{} – {}", NAMES[i], NAMES[j]) }</td>
+                        <td>
+                            { glyphs::inline(
+                                self.props.glyph_style,
+                                glyphs::ASPECT_CHARACTERS[asp.aspect_type as usize],
+                                glyphs::ASPECTS[asp.aspect_type as usize],
+                            ) }
+                            { " " }
+                            { asp.aspect_type.name() }
+                        </td>
+                        <td>{ format!("{:.2}°", asp.orb) }</td>
+                        <td>{ format!("{:.2}°", asp.orb / harmonic) }</td>
+                    </tr>
+                }
+            });
+        let contacts = if self.props.antiscia {
+            antiscia::contacts(bodies, orbis)
+        } else {
+            Vec::new()
+        };
+        let contact_rows = contacts.iter().map(|contact| {
+            html! {
+                <tr>
+                    <td>{ format!("{} – {}", NAMES[contact.first], NAMES[contact.second]) }</td>
+                    <td>{ contact.reflection.name() }</td>
+                    <td>{ format!("{:.2}°", contact.orb) }</td>
+                    <td>{ format!("{:.2}°", contact.orb / harmonic) }</td>
+                </tr>
+            }
+        });
+        html! {
+            <table class="table table-sm table-hover">
+                <thead>
+                    <tr>
+                        <th>{ "Pair" }</th>
+                        <th>{ "Aspect" }</th>
+                        <th>{ "Orb" }</th>
+                        <th>{ "Radix orb" }</th>
+                    </tr>
+                </thead>
+                <tbody>{ for rows }{ for contact_rows }</tbody>
+            </table>
+        }
+    }
+}
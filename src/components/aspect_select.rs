@@ -0,0 +1,65 @@
+use yew::prelude::*;
+
+use crate::aspect::{Selection, Type};
+
+pub struct AspectSelect {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub selection: Selection,
+    pub on_change: Callback<Selection>,
+}
+
+pub struct Msg(Type);
+
+impl Component for AspectSelect {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let Msg(aspect_type) = msg;
+        let mut selection = self.props.selection;
+        selection.toggle(aspect_type);
+        self.props.on_change.emit(selection);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let checkbox = |aspect_type: Type| {
+            let id = format!("aspect-type-{}", aspect_type as usize);
+            html! {
+                <div class="form-check form-check-inline">
+                    <input
+                        id=&id
+                        class="form-check-input"
+                        type="checkbox"
+                        checked=self.props.selection.contains(aspect_type)
+                        onchange=self.link.callback(move |_| Msg(aspect_type))
+                    />
+                    <label for=id class="form-check-label">{ aspect_type.name() }</label>
+                </div>
+            }
+        };
+        html! {
+            <div class="form-group">
+                { for Type::ALL.iter().copied().map(checkbox) }
+            </div>
+        }
+    }
+}
@@ -1,10 +1,8 @@
 use super::drawing::HarmonicCycle;
+use crate::harmonic;
 use itertools::Itertools;
 use yew::prelude::*;
 
-static BASE_TEXT: &str = "2-D Radix\nHorizon view\nOrigo: Tropos";
-static CYCLE_TEXT: &str = "2-D Turned\nDerived houses\nRadix Quadrants";
-
 pub struct BottomBar {
     harmonic_cycle: HarmonicCycle,
 }
@@ -34,9 +32,25 @@ impl Component for BottomBar {
     }
 
     fn view(&self) -> Html {
-        let text = match self.harmonic_cycle {
-            HarmonicCycle::Cycle(_) => CYCLE_TEXT,
-            _ => BASE_TEXT,
+        let title = match (
+            self.harmonic_cycle.is_harmonic(),
+            self.harmonic_cycle.is_turned(),
+        ) {
+            (false, false) => "2-D Radix".to_string(),
+            (false, true) => "2-D Turned".to_string(),
+            (true, false) => format!(
+                "2-D Harmonic {}",
+                harmonic::describe(self.harmonic_cycle.harmonic)
+            ),
+            (true, true) => format!(
+                "2-D Harmonic {} Turned",
+                harmonic::describe(self.harmonic_cycle.harmonic)
+            ),
+        };
+        let text = if self.harmonic_cycle.is_turned() {
+            format!("{}\nDerived houses\nRadix Quadrants", title)
+        } else {
+            format!("{}\nHorizon view\nOrigo: Tropos", title)
         };
         html! {
             <div>{
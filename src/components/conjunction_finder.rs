@@ -0,0 +1,204 @@
+use yew::prelude::*;
+
+use super::IntegerInput;
+use crate::app::{try_from_change_data, Positions, NAMES};
+use crate::harmonic::{self, Conjunction};
+
+const MAX_ROWS: usize = 100;
+
+pub struct ConjunctionFinder {
+    link: ComponentLink<Self>,
+    props: Props,
+    max_harmonic: u16,
+    orb: u16,
+    body: Option<usize>,
+    sort: SortKey,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub positions: Positions,
+    pub planets: u16,
+    /// The harmonic currently drawn, if it is a whole number
+    pub harmonic: Option<u16>,
+    pub on_select: Callback<u16>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SortKey {
+    Pair,
+    Harmonic,
+    Orb,
+}
+
+pub enum Msg {
+    MaxHarmonicChange(u16),
+    OrbChange(u16),
+    BodyChange(Option<usize>),
+    Sort(SortKey),
+    Select(u16),
+}
+
+impl Component for ConjunctionFinder {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            max_harmonic: 32,
+            orb: harmonic::CONJUNCTION_ORB as u16,
+            body: None,
+            sort: SortKey::Pair,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::MaxHarmonicChange(max_harmonic) => {
+                self.max_harmonic = max_harmonic.clamp(1, harmonic::MAX_WHOLE_HARMONIC)
+            }
+            Msg::OrbChange(orb) => self.orb = orb,
+            Msg::BodyChange(body) => self.body = body,
+            Msg::Sort(sort) => self.sort = sort,
+            Msg::Select(harmonic) => {
+                self.props.on_select.emit(harmonic);
+                return false;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let on_max_change = self.link.callback(Msg::MaxHarmonicChange);
+        let on_orb_change = self.link.callback(Msg::OrbChange);
+        let on_body_change = self
+            .link
+            .callback(|cd| Msg::BodyChange(try_from_change_data(cd).ok()));
+        let bodies = self.bodies();
+        let conjunctions = self.conjunctions(&bodies);
+        let hidden = conjunctions.len().saturating_sub(MAX_ROWS);
+        let header = |name: &str, sort: SortKey| {
+            let class = if sort == self.sort {
+                "table-active"
+            } else {
+                ""
+            };
+            html! {
+                <th class=class onclick=self.link.callback(move |_| Msg::Sort(sort))>
+                    { name }
+                </th>
+            }
+        };
+        html! {
+            <div>
+                <div class="form-row">
+                    <div class="form-group col">
+                        <label for="conjunction-max">{ "Highest harmonic" }</label>
+                        <IntegerInput
+                            id="conjunction-max"
+                            value=self.max_harmonic
+                            min=1
+                            max=harmonic::MAX_WHOLE_HARMONIC
+                            on_change=on_max_change
+                        />
+                    </div>
+                    <div class="form-group col">
+                        <label for="conjunction-orb">{ "Orb" }</label>
+                        <IntegerInput
+                            id="conjunction-orb"
+                            value=self.orb
+                            min=1
+                            max=15
+                            on_change=on_orb_change
+                        />
+                    </div>
+                    <div class="form-group col">
+                        <label for="conjunction-body">{ "Body" }</label>
+                        <select id="conjunction-body" class="form-control" onchange=on_body_change>
+                            <option value="" selected=self.body.is_none()>{ "All" }</option>
+                            { for (0..bodies.len()).map(|i| html! {
+                                <option value=i selected=self.body == Some(i)>{ NAMES[i] }</option>
+                            }) }
+                        </select>
+                    </div>
+                </div>
+                <table class="table table-sm table-hover">
+                    <thead>
+                        <tr>
+                            { header("Pair", SortKey::Pair) }
+                            { header("Harmonic", SortKey::Harmonic) }
+                            { header("Orb", SortKey::Orb) }
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for conjunctions.iter().take(MAX_ROWS).map(|c| self.format_row(c)) }
+                    </tbody>
+                </table>
+                {
+                    if hidden > 0 {
+                        html! { <p>{ format!("{} more not shown", hidden) }</p> }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+impl ConjunctionFinder {
+    fn bodies(&self) -> Vec<f32> {
+        self.props
+            .positions
+            .planets_without_node()
+            .iter()
+            .take(self.props.planets as usize + 2)
+            .copied()
+            .collect()
+    }
+
+    /// The conjunctions involving the selected body, in the selected order
+    fn conjunctions(&self, bodies: &[f32]) -> Vec<Conjunction> {
+        let mut conjunctions: Vec<Conjunction> =
+            harmonic::find_conjunctions(bodies, self.max_harmonic, f32::from(self.orb))
+                .into_iter()
+                .filter(|c| match self.body {
+                    Some(body) => c.first == body || c.second == body,
+                    None => true,
+                })
+                .collect();
+        match self.sort {
+            SortKey::Pair => (),
+            SortKey::Harmonic => conjunctions.sort_by_key(|c| c.harmonic),
+            SortKey::Orb => conjunctions.sort_by(|a, b| a.orb.partial_cmp(&b.orb).unwrap()),
+        }
+        conjunctions
+    }
+
+    fn format_row(&self, conjunction: &Conjunction) -> Html {
+        let harmonic = conjunction.harmonic;
+        let class = if Some(harmonic) == self.props.harmonic {
+            "table-active"
+        } else {
+            ""
+        };
+        html! {
+            <tr class=class onclick=self.link.callback(move |_| Msg::Select(harmonic))>
+                <td>{ format!("{} – {}", NAMES[conjunction.first], NAMES[conjunction.second]) }</td>
+                <td>{ harmonic }</td>
+                <td>{ format!("{:.2}°", conjunction.orb) }</td>
+            </tr>
+        }
+    }
+}
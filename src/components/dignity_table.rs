@@ -0,0 +1,88 @@
+use yew::prelude::*;
+
+use crate::app::{Positions, GLYPHS, NAMES};
+use crate::dignities::{self, Dignity};
+
+/// The essential dignities of the traditional planets. Each column shows the ruler of that
+/// dignity where the planet stands, in bold when it is the planet itself.
+pub struct DignityTable {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub positions: Positions,
+}
+
+impl Component for DignityTable {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let ruler = |ruler: Option<usize>, has: bool| match ruler {
+            Some(ruler) if has => html! { <td><strong>{ GLYPHS[ruler] }</strong></td> },
+            Some(ruler) => html! { <td>{ GLYPHS[ruler] }</td> },
+            None => html! { <td>{ "–" }</td> },
+        };
+        let row = |d: &Dignity| {
+            let mut notes = Vec::new();
+            if d.detriment {
+                notes.push("Detriment");
+            }
+            if d.fall {
+                notes.push("Fall");
+            }
+            if d.is_peregrine() {
+                notes.push("Peregrine");
+            }
+            html! {
+                <tr>
+                    <td>{ NAMES[d.planet] }</td>
+                    { ruler(Some(d.rulers.domicile), d.domicile) }
+                    { ruler(d.rulers.exaltation, d.exaltation) }
+                    { ruler(Some(d.rulers.triplicity), d.triplicity) }
+                    { ruler(Some(d.rulers.term), d.term) }
+                    { ruler(Some(d.rulers.face), d.face) }
+                    <td>{ notes.join(", ") }</td>
+                    <td>{ format!("{:+}", d.score()) }</td>
+                </tr>
+            }
+        };
+        html! {
+            <table class="table table-sm table-hover">
+                <thead>
+                    <tr>
+                        <th>{ "Planet" }</th>
+                        <th>{ "Domicile" }</th>
+                        <th>{ "Exaltation" }</th>
+                        <th>{ "Triplicity" }</th>
+                        <th>{ "Term" }</th>
+                        <th>{ "Face" }</th>
+                        <th>{ "Debility" }</th>
+                        <th>{ "Score" }</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    { for dignities::dignities(&self.props.positions).iter().map(row) }
+                </tbody>
+            </table>
+        }
+    }
+}
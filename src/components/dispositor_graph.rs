@@ -0,0 +1,211 @@
+use itertools::Itertools;
+use yew::prelude::*;
+
+use crate::app::{Positions, GLYPHS};
+use crate::dispositors::{Dispositors, Reception, Rulership, PLANETS};
+
+const LAYOUT_RADIUS: f32 = 75.;
+const NODE_RADIUS: f32 = 9.;
+
+/// A directed graph from each planet to its dispositor, with the planets placed round a circle
+pub struct DispositorGraph {
+    link: ComponentLink<Self>,
+    props: Props,
+    rulership: Rulership,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub positions: Positions,
+}
+
+pub enum Msg {
+    RulershipChange(Rulership),
+}
+
+impl Component for DispositorGraph {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            rulership: Rulership::Traditional,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RulershipChange(rulership) => self.rulership = rulership,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let dispositors = Dispositors::new(&self.props.positions, self.rulership);
+        let rulership_button = |rulership: Rulership| {
+            let class = if rulership == self.rulership {
+                "btn btn-secondary active"
+            } else {
+                "btn btn-secondary"
+            };
+            html! {
+                <button
+                    class=class
+                    onclick=self.link.callback(move |_| Msg::RulershipChange(rulership))
+                >
+                    { rulership.name() }
+                </button>
+            }
+        };
+        html! {
+            <div>
+                <div class="btn-group btn-group-sm" role="group">
+                    { rulership_button(Rulership::Traditional) }
+                    { rulership_button(Rulership::Modern) }
+                </div>
+                { graph(&dispositors) }
+                { summary(&dispositors) }
+            </div>
+        }
+    }
+}
+
+/// The centre of a planet's node, going clockwise from the top
+fn node_centre(planet: usize) -> (f32, f32) {
+    let angle = (360. * planet as f32 / PLANETS as f32).to_radians();
+    (LAYOUT_RADIUS * angle.sin(), -LAYOUT_RADIUS * angle.cos())
+}
+
+fn graph(dispositors: &Dispositors) -> Html {
+    let edge = |planet: usize| {
+        let ruler = dispositors.rulers[planet];
+        if ruler == planet {
+            return html! {};
+        }
+        let (x0, y0) = node_centre(planet);
+        let (x1, y1) = node_centre(ruler);
+        // Stop short of the nodes so that the arrow heads stay visible
+        let length = (x1 - x0).hypot(y1 - y0);
+        let (ux, uy) = ((x1 - x0) / length, (y1 - y0) / length);
+        let stroke = if dispositors.in_reception(planet, ruler, Reception::Domicile) {
+            "#aa0000"
+        } else {
+            "black"
+        };
+        html! {
+            <path
+                d=format!(
+                    "M {} {} L {} {}",
+                    x0 + ux * NODE_RADIUS,
+                    y0 + uy * NODE_RADIUS,
+                    x1 - ux * (NODE_RADIUS + 2.),
+                    y1 - uy * (NODE_RADIUS + 2.),
+                )
+                stroke=stroke
+                marker-end="url(#dispositor-arrow)"
+            />
+        }
+    };
+    let exaltation = |(a, b): (usize, usize)| {
+        if dispositors.in_reception(a, b, Reception::Exaltation) {
+            let (x0, y0) = node_centre(a);
+            let (x1, y1) = node_centre(b);
+            html! {
+                <path
+                    d=format!("M {} {} L {} {}", x0, y0, x1, y1)
+                    stroke="#00aa00"
+                    stroke-dasharray="3 2"
+                />
+            }
+        } else {
+            html! {}
+        }
+    };
+    let node = |planet: usize| {
+        let (x, y) = node_centre(planet);
+        // Planets in their own sign get a double ring
+        let ring = if dispositors.finals.contains(&planet) {
+            html! { <circle cx=x cy=y r=NODE_RADIUS + 2. fill="transparent" stroke="black" /> }
+        } else {
+            html! {}
+        };
+        html! {
+            <>
+                { ring }
+                <circle cx=x cy=y r=NODE_RADIUS fill="#55ffff" stroke="black" />
+                <text x=x y=y text-anchor="middle" dominant-baseline="central" font-size="10">
+                    { GLYPHS[planet] }
+                </text>
+            </>
+        }
+    };
+    html! {
+        <svg class="img-fluid"
+            version="1.1" baseProfile="full"
+            xmlns="http://www.w3.org/2000/svg"
+            viewBox="-90 -90 180 180"
+        >
+            <defs>
+                <marker
+                    id="dispositor-arrow"
+                    viewBox="0 0 10 10"
+                    refX="10"
+                    refY="5"
+                    markerWidth="5"
+                    markerHeight="5"
+                    orient="auto"
+                >
+                    <path d="M 0 0 L 10 5 L 0 10 z" />
+                </marker>
+            </defs>
+            <g stroke-width="0.8">
+                { for (0..PLANETS).tuple_combinations().map(exaltation) }
+                { for (0..PLANETS).map(edge) }
+            </g>
+            { for (0..PLANETS).map(node) }
+        </svg>
+    }
+}
+
+fn summary(dispositors: &Dispositors) -> Html {
+    let final_dispositor = match dispositors.final_dispositor() {
+        Some(planet) => format!("Final dispositor: {}", GLYPHS[planet]),
+        None => "No single final dispositor".to_string(),
+    };
+    let loops = dispositors.loops.iter().map(|cycle| {
+        let chain = cycle
+            .iter()
+            .chain(cycle.first())
+            .map(|&p| GLYPHS[p])
+            .join(" → ");
+        html! { <li>{ format!("Loop: {}", chain) }</li> }
+    });
+    let receptions = dispositors.receptions.iter().map(|r| {
+        html! {
+            <li>{ format!(
+                "Mutual reception by {}: {} {}",
+                r.reception.name(),
+                GLYPHS[r.first],
+                GLYPHS[r.second],
+            ) }</li>
+        }
+    });
+    html! {
+        <ul class="list-unstyled">
+            <li>{ final_dispositor }</li>
+            { for loops }
+            { for receptions }
+        </ul>
+    }
+}
@@ -1,29 +1,77 @@
+use itertools::Itertools;
+use log::error;
 use yew::prelude::*;
 
-use super::{BottomBar, CycleSelect, HarmonicSelect, PlanetSelect, SvgChart, TopBar};
-use crate::app::{harmonics, Positions};
+use super::{
+    AgeHarmonic, AspectList, AspectSelect, BottomBar, ConjunctionFinder, CycleSelect,
+    DispositorGraph, ExportControls, HarmonicGrid, HarmonicSelect, HarmonicSpectrum, HarmonicSweep,
+    OrbSelect, PlanetSelect, ShapeSummary, SvgChart, ThemeSelect, TopBar,
+};
+use crate::app::{harmonics, try_from_change_data, Angles, Positions, NAMES};
+use crate::aspect;
+use crate::date::DateTime;
+use crate::export::{self, Metadata};
+use crate::glyphs::GlyphStyle;
+use crate::harmonic;
+use crate::layout::{ChartStyle, Layout};
+use crate::lots::LotPosition;
+use crate::sheet;
+use crate::theme::Theme;
+use crate::wheel::Wheel;
 
 pub struct Drawing {
     link: ComponentLink<Self>,
     props: Props,
     harmonic_cycle: HarmonicCycle,
     aspect: bool,
+    aspect_types: aspect::Selection,
+    orb: aspect::Orb,
+    antiscia: bool,
     planets: u16,
+    glyph_style: GlyphStyle,
+    chart_style: ChartStyle,
+    /// Write the degrees and minutes of each body by its glyph
+    position_labels: bool,
+    /// The built-in themes followed by any that were imported
+    themes: Vec<Theme>,
+    theme: usize,
+    /// Why the last export failed, if it did
+    export_error: Option<String>,
+    /// Kept so that the props of the harmonic views only change when their content does,
+    /// which keeps them from redrawing on every frame of a sweep
+    on_harmonic_select: Callback<u16>,
 }
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct Props {
     pub positions: Positions,
+    pub birth: Option<DateTime>,
+    /// The daily motion of the bodies from the Sun to the Node, where it is known
+    #[prop_or_default]
+    pub speeds: [Option<f32>; 11],
+    /// The lots in the radix
+    #[prop_or_default]
+    pub lots: Vec<LotPosition>,
 }
 
 pub enum Msg {
     #[allow(unused)]
     Noop,
     ToggleAspect,
+    AspectTypesChange(aspect::Selection),
+    OrbChange(aspect::Orb),
+    ToggleAntiscia,
     CycleChange(u16),
-    HarmonicChange(u16),
+    HarmonicChange(f32),
+    ToggleFixedAngles,
     PlanetsChange(u16),
+    GlyphStyleChange(usize),
+    ChartStyleChange(usize),
+    TogglePositionLabels,
+    ThemeChange(usize),
+    ThemeImport(Box<Theme>),
     Reset,
+    Export(export::Format),
 }
 
 impl Component for Drawing {
@@ -31,32 +79,69 @@ impl Component for Drawing {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let on_harmonic_select = link.callback(|h| Msg::HarmonicChange(f32::from(h)));
         Self {
             link,
             props,
             harmonic_cycle: HarmonicCycle::default(),
             aspect: false,
+            aspect_types: aspect::Selection::default(),
+            orb: aspect::Orb::default(),
+            antiscia: false,
             planets: 9,
+            glyph_style: GlyphStyle::Outline,
+            chart_style: ChartStyle::Standard,
+            position_labels: false,
+            themes: Theme::built_in(),
+            theme: 0,
+            export_error: None,
+            on_harmonic_select,
         }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
-            Msg::CycleChange(0) | Msg::HarmonicChange(1) => {
-                self.harmonic_cycle = HarmonicCycle::Base
-            }
-            Msg::CycleChange(cycle) => self.harmonic_cycle = HarmonicCycle::Cycle(cycle),
-            Msg::HarmonicChange(harmonic) => {
-                self.harmonic_cycle = HarmonicCycle::Harmonic(harmonic)
+            Msg::CycleChange(cycle) => self.harmonic_cycle.cycle = cycle,
+            Msg::HarmonicChange(harmonic) => self.harmonic_cycle.harmonic = harmonic,
+            Msg::ToggleFixedAngles => {
+                self.harmonic_cycle.angles = match self.harmonic_cycle.angles {
+                    Angles::Harmonised => Angles::Fixed,
+                    Angles::Fixed => Angles::Harmonised,
+                }
             }
             Msg::Noop => return false,
             Msg::ToggleAspect => self.aspect = !self.aspect,
+            Msg::AspectTypesChange(aspect_types) => self.aspect_types = aspect_types,
+            Msg::OrbChange(orb) => self.orb = orb,
+            Msg::ToggleAntiscia => self.antiscia = !self.antiscia,
             Msg::PlanetsChange(planets) => self.planets = planets,
+            Msg::GlyphStyleChange(i) => self.glyph_style = GlyphStyle::ALL[i],
+            Msg::ChartStyleChange(i) => self.chart_style = ChartStyle::ALL[i],
+            Msg::TogglePositionLabels => self.position_labels = !self.position_labels,
+            Msg::ThemeChange(i) => self.theme = i,
+            Msg::ThemeImport(theme) => {
+                // A theme replaces any other of the same name
+                if let Some(i) = self.themes.iter().position(|t| t.name == theme.name) {
+                    self.themes[i] = *theme;
+                    self.theme = i;
+                } else {
+                    self.themes.push(*theme);
+                    self.theme = self.themes.len() - 1;
+                }
+            }
             Msg::Reset => {
                 self.harmonic_cycle = HarmonicCycle::default();
                 self.aspect = false;
+                self.aspect_types = aspect::Selection::default();
+                self.orb = aspect::Orb::default();
+                self.antiscia = false;
                 self.planets = 9;
+                self.glyph_style = GlyphStyle::Outline;
+                self.chart_style = ChartStyle::Standard;
+                self.position_labels = false;
+                self.theme = 0;
             }
+            Msg::Export(format) => self.export_error = self.export(format).err(),
         }
         true
     }
@@ -71,31 +156,43 @@ impl Component for Drawing {
     }
 
     fn view(&self) -> Html {
-        let on_harmonic_change = self.link.callback(Msg::HarmonicChange);
-        let on_cycle_change = self.link.callback(Msg::CycleChange);
         let on_aspect_toggle = self.link.callback(|_| Msg::ToggleAspect);
+        let on_aspect_types_change = self.link.callback(Msg::AspectTypesChange);
         let on_planets_change = self.link.callback(Msg::PlanetsChange);
         let on_reset = self.link.callback(|_| Msg::Reset);
-
-        let (harmonic, cycle) = match self.harmonic_cycle {
-            HarmonicCycle::Base => (1, 0),
-            HarmonicCycle::Harmonic(h) => (h, 0),
-            HarmonicCycle::Cycle(c) => (1, c),
+        let harmonic = self.harmonic_cycle.harmonic;
+        let drawing_positions = self.drawing_positions();
+        let maybe_export_error = match &self.export_error {
+            Some(err) => html! { <div class="alert alert-warning">{ err }</div> },
+            None => html! {},
         };
 
-        let drawing_positions = harmonics(&self.props.positions, harmonic);
-
         html! {
             <>
             <div class="col-md-5">
-                <TopBar />
+                <TopBar harmonic_cycle=&self.harmonic_cycle />
                 <SvgChart
                     harmonic_cycle=&self.harmonic_cycle
                     positions=&drawing_positions
                     aspect=self.aspect
+                    aspect_types=self.aspect_types
+                    orb=self.orb.in_harmonic_chart(harmonic)
                     planets=self.planets
+                    antiscia=self.antiscia
+                    lots=self.drawing_lots()
+                    speeds=self.drawing_speeds()
+                    glyph_style=self.glyph_style
+                    layout=self.layout()
+                    theme=self.theme()
+                    focusable=true
                 />
                 <BottomBar harmonic_cycle=&self.harmonic_cycle />
+                <ExportControls on_export=self.link.callback(Msg::Export) />
+                { maybe_export_error }
+                <h4>{ "Chart shape" }</h4>
+                <ShapeSummary positions=&drawing_positions />
+                <h4>{ "Dispositors" }</h4>
+                <DispositorGraph positions=&self.props.positions />
             </div>
             <div class="col">
                 <h4>{ "Drawing controls" }</h4>
@@ -112,28 +209,364 @@ impl Component for Drawing {
                             { "Show aspects" }
                         </label>
                     </div>
-                    <HarmonicSelect harmonic=harmonic on_change=on_harmonic_change />
-                    <CycleSelect cycle=cycle on_change=on_cycle_change />
+                    <AspectSelect
+                        selection=self.aspect_types
+                        on_change=on_aspect_types_change
+                    />
+                    <OrbSelect
+                        orb=self.orb
+                        harmonic=harmonic
+                        on_change=self.link.callback(Msg::OrbChange)
+                    />
+                    <div class="form-check">
+                        <input
+                            id="antiscia-toggle"
+                            class="form-check-input"
+                            type="checkbox"
+                            checked=self.antiscia
+                            onchange=self.link.callback(|_| Msg::ToggleAntiscia)
+                        />
+                        <label for="antiscia-toggle" class="form-check-label">
+                            { "Show antiscia and contra-antiscia" }
+                        </label>
+                    </div>
+                    { self.harmonic_controls() }
                     <PlanetSelect planets=self.planets on_change=on_planets_change />
+                    { self.style_controls() }
                     <button type="button" class="btn btn-secondary" onclick=on_reset>
                         { "Reset" }
                     </button>
                 </div>
+                { self.analysis(&drawing_positions) }
             </div>
             </>
         }
     }
 }
 
+impl Drawing {
+    /// The choice of harmonic and of the houses that are turned to the horizon
+    fn harmonic_controls(&self) -> Html {
+        let HarmonicCycle {
+            harmonic,
+            cycle,
+            angles,
+        } = self.harmonic_cycle;
+        html! {
+            <>
+                <HarmonicSelect
+                    harmonic=harmonic
+                    on_change=self.link.callback(Msg::HarmonicChange)
+                />
+                <div class="form-check">
+                    <input
+                        id="fixed-angles-toggle"
+                        class="form-check-input"
+                        type="checkbox"
+                        checked=angles == Angles::Fixed
+                        onchange=self.link.callback(|_| Msg::ToggleFixedAngles)
+                    />
+                    <label for="fixed-angles-toggle" class="form-check-label">
+                        { "Keep the radix angles in harmonic charts" }
+                    </label>
+                </div>
+                <AgeHarmonic
+                    birth=self.props.birth
+                    on_change=self.link.callback(Msg::HarmonicChange)
+                />
+                <HarmonicSweep
+                    harmonic=harmonic
+                    on_change=self.link.callback(Msg::HarmonicChange)
+                />
+                <CycleSelect cycle=cycle on_change=self.link.callback(Msg::CycleChange) />
+            </>
+        }
+    }
+
+    /// The glyphs, rings and colours of the wheel
+    fn style_controls(&self) -> Html {
+        html! {
+            <>
+                <div class="form-group">
+                    <label for="glyph-style">{ "Glyphs" }</label>
+                    <select
+                        id="glyph-style"
+                        class="form-control"
+                        onchange=self.link.callback(|cd| {
+                            Msg::GlyphStyleChange(try_from_change_data(cd).unwrap_or(0))
+                        })
+                    >
+                        { for GlyphStyle::ALL.iter().enumerate().map(|(i, &style)| html! {
+                            <option value=i selected=self.glyph_style == style>
+                                { style.name() }
+                            </option>
+                        }) }
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label for="chart-style">{ "Chart style" }</label>
+                    <select
+                        id="chart-style"
+                        class="form-control"
+                        onchange=self.link.callback(|cd| {
+                            Msg::ChartStyleChange(try_from_change_data(cd).unwrap_or(0))
+                        })
+                    >
+                        { for ChartStyle::ALL.iter().enumerate().map(|(i, &style)| html! {
+                            <option value=i selected=self.chart_style == style>
+                                { style.name() }
+                            </option>
+                        }) }
+                    </select>
+                </div>
+                <div class="form-check">
+                    <input
+                        id="position-labels-toggle"
+                        class="form-check-input"
+                        type="checkbox"
+                        checked=self.position_labels
+                        onchange=self.link.callback(|_| Msg::TogglePositionLabels)
+                    />
+                    <label for="position-labels-toggle" class="form-check-label">
+                        { "Write the positions next to the glyphs" }
+                    </label>
+                </div>
+                <ThemeSelect
+                    themes=&self.themes
+                    selected=self.theme
+                    on_select=self.link.callback(Msg::ThemeChange)
+                    on_import=self.link.callback(|theme| Msg::ThemeImport(Box::new(theme)))
+                />
+            </>
+        }
+    }
+
+    /// The aspects and harmonic analyses of the chart
+    fn analysis(&self, drawing_positions: &Positions) -> Html {
+        let harmonic = self.harmonic_cycle.harmonic;
+        let whole_harmonic = harmonic::whole(harmonic);
+        html! {
+            <>
+                <h4>{ "Aspects" }</h4>
+                <AspectList
+                    positions=drawing_positions
+                    harmonic=harmonic
+                    planets=self.planets
+                    aspect_types=self.aspect_types
+                    orb=self.orb
+                    antiscia=self.antiscia
+                    glyph_style=self.glyph_style
+                />
+                <h4>{ "Harmonic spectrum" }</h4>
+                <HarmonicSpectrum
+                    positions=&self.props.positions
+                    planets=self.planets
+                    harmonic=whole_harmonic
+                    on_select=&self.on_harmonic_select
+                />
+                <h4>{ "Harmonic grid" }</h4>
+                <HarmonicGrid
+                    positions=&self.props.positions
+                    aspect=self.aspect
+                    aspect_types=self.aspect_types
+                    orb=self.orb
+                    planets=self.planets
+                    angles=self.harmonic_cycle.angles
+                    glyph_style=self.glyph_style
+                    theme=self.theme()
+                    on_select=&self.on_harmonic_select
+                />
+                <h4>{ "Harmonic conjunctions" }</h4>
+                <ConjunctionFinder
+                    positions=&self.props.positions
+                    planets=self.planets
+                    harmonic=whole_harmonic
+                    on_select=&self.on_harmonic_select
+                />
+            </>
+        }
+    }
+
+    /// The rings of the chosen chart style, with room for the position labels if they are shown
+    fn layout(&self) -> Layout {
+        let layout = self.chart_style.layout();
+        if self.position_labels {
+            layout.with_position_labels()
+        } else {
+            layout
+        }
+    }
+
+    fn theme(&self) -> &Theme {
+        &self.themes[self.theme]
+    }
+
+    fn drawing_positions(&self) -> Positions {
+        harmonics(
+            &self.props.positions,
+            self.harmonic_cycle.harmonic,
+            self.harmonic_cycle.angles,
+        )
+    }
+
+    /// The daily motion of the bodies in the harmonic chart, which is as many times faster
+    fn drawing_speeds(&self) -> [Option<f32>; 11] {
+        let harmonic = self.harmonic_cycle.harmonic;
+        let mut speeds = self.props.speeds;
+        for speed in speeds.iter_mut().flatten() {
+            *speed *= harmonic;
+        }
+        speeds
+    }
+
+    fn drawing_lots(&self) -> Vec<LotPosition> {
+        self.props
+            .lots
+            .iter()
+            .map(|lot| LotPosition {
+                position: (lot.position * self.harmonic_cycle.harmonic) % 360.,
+                ..lot.clone()
+            })
+            .collect()
+    }
+
+    fn metadata(&self) -> Metadata<'_> {
+        let HarmonicCycle {
+            harmonic,
+            cycle,
+            angles,
+        } = self.harmonic_cycle;
+        let title = if self.harmonic_cycle.is_harmonic() {
+            format!("Harmonic {} chart", harmonic::describe(harmonic))
+        } else {
+            "Radix chart".to_string()
+        };
+        let aspect_types = aspect::Type::ALL
+            .iter()
+            .filter(|&&t| self.aspect_types.contains(t))
+            .map(|t| t.name())
+            .join(", ");
+        let settings = vec![
+            ("zodiac", "tropical".to_string()),
+            ("houses", "equal".to_string()),
+            ("harmonic", harmonic::describe(harmonic)),
+            (
+                "angles",
+                match angles {
+                    Angles::Harmonised => "harmonised",
+                    Angles::Fixed => "radix",
+                }
+                .to_string(),
+            ),
+            ("horizon-house", (cycle + 1).to_string()),
+            ("aspects", self.aspect.to_string()),
+            ("aspect-types", aspect_types),
+            ("orb", self.orb.in_harmonic_chart(harmonic).to_string()),
+            ("planets", self.planets.to_string()),
+            ("antiscia", self.antiscia.to_string()),
+            ("chart-style", self.chart_style.name().to_string()),
+            ("position-labels", self.position_labels.to_string()),
+            ("theme", self.theme().name.clone()),
+            (
+                "birth",
+                self.props
+                    .birth
+                    .map_or_else(String::new, |birth| birth.to_string()),
+            ),
+        ];
+        let points = NAMES
+            .iter()
+            .copied()
+            .zip(self.props.positions.0.iter().copied())
+            .chain(
+                self.props
+                    .lots
+                    .iter()
+                    .map(|lot| (lot.name.as_str(), lot.position)),
+            )
+            .collect();
+        Metadata {
+            title,
+            settings,
+            points,
+        }
+    }
+
+    /// Save the drawing as a file
+    fn export(&self, format: export::Format) -> Result<(), String> {
+        let positions = self.drawing_positions();
+        let lots = self.drawing_lots();
+        let wheel = Wheel {
+            positions: &positions,
+            cycle: self.harmonic_cycle.cycle,
+            aspect: self.aspect,
+            aspect_types: self.aspect_types,
+            orb: self.orb.in_harmonic_chart(self.harmonic_cycle.harmonic),
+            planets: self.planets,
+            layout: self.layout(),
+            antiscia: self.antiscia,
+            lots: &lots,
+            speeds: &self.drawing_speeds(),
+            glyph_style: GlyphStyle::Outline,
+            theme: self.theme(),
+            focus: None,
+            interactive: false,
+        };
+        let result = match format {
+            export::Format::Svg { size } => {
+                let document = export::svg_document(&wheel, &self.metadata(), size);
+                export::download("chart.svg", document.as_bytes())
+            }
+            #[cfg(feature = "png")]
+            export::Format::Png {
+                size,
+                dpi,
+                background,
+            } => match export::png(&wheel, &self.metadata(), size, dpi, background) {
+                Ok(image) => export::download("chart.png", &image),
+                Err(err) => Err(err.into()),
+            },
+            export::Format::Pdf { paper } => {
+                let sheet = sheet::chart_sheet(&wheel, &self.metadata(), paper);
+                export::download("chart.pdf", &sheet)
+            }
+            #[cfg(not(feature = "png"))]
+            export::Format::Png { .. } => Err("This build was made without PNG export".into()),
+        };
+        result.map_err(|err| {
+            error!("Could not download the chart: {:?}", err);
+            let reason = err.as_string().unwrap_or_else(|| format!("{:?}", err));
+            format!("Could not save the chart: {}", reason)
+        })
+    }
+}
+
+/// How the drawing is derived from the radix: the positions are multiplied by `harmonic` and
+/// the houses are turned so that the cusp of house `cycle + 1` lies on the horizon. Both can
+/// be active at once.
 #[derive(Clone, Debug, PartialEq)]
-pub enum HarmonicCycle {
-    Base,
-    Cycle(u16),
-    Harmonic(u16),
+pub struct HarmonicCycle {
+    pub harmonic: f32,
+    pub cycle: u16,
+    pub angles: Angles,
 }
 
 impl Default for HarmonicCycle {
     fn default() -> Self {
-        Self::Base
+        Self {
+            harmonic: 1.,
+            cycle: 0,
+            angles: Angles::Harmonised,
+        }
+    }
+}
+
+impl HarmonicCycle {
+    pub fn is_harmonic(&self) -> bool {
+        (self.harmonic - 1.).abs() > std::f32::EPSILON
+    }
+
+    pub fn is_turned(&self) -> bool {
+        self.cycle != 0
     }
 }
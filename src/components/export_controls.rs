@@ -0,0 +1,225 @@
+use yew::prelude::*;
+
+use super::IntegerInput;
+use crate::app::try_from_change_data;
+use crate::export::{Background, Format, MAX_PNG_SIZE};
+use crate::sheet::Paper;
+
+/// The range of image sizes in pixels
+const SIZES: (u16, u16) = (100, 8000);
+/// The range of print resolutions in dots per inch
+const DPIS: (u16, u16) = (36, 2400);
+
+/// Settings for saving the chart as a file, and the buttons that do it
+pub struct ExportControls {
+    link: ComponentLink<Self>,
+    props: Props,
+    size: u16,
+    dpi: u16,
+    transparent: bool,
+    /// The background colour as `#rrggbb`, as an `<input type="color">` gives it
+    colour: String,
+    paper: Paper,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub on_export: Callback<Format>,
+}
+
+pub enum Msg {
+    SizeChange(u16),
+    DpiChange(u16),
+    ToggleTransparent,
+    ColourChange(ChangeData),
+    PaperChange(usize),
+    ExportSvg,
+    ExportPng,
+    ExportPdf,
+}
+
+/// Parse a colour of the form `#rrggbb`
+fn parse_colour(colour: &str) -> Option<(u8, u8, u8)> {
+    let hex = colour.strip_prefix('#').filter(|hex| hex.len() == 6)?;
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+impl ExportControls {
+    fn background(&self) -> Background {
+        match parse_colour(&self.colour) {
+            Some((red, green, blue)) if !self.transparent => Background::Solid(red, green, blue),
+            _ => Background::Transparent,
+        }
+    }
+
+    /// Larger images can take more memory than the browser tab has
+    fn too_large_for_png(&self) -> bool {
+        cfg!(feature = "png") && u32::from(self.size) > MAX_PNG_SIZE
+    }
+
+    fn size_warning(&self) -> Html {
+        if self.too_large_for_png() {
+            html! {
+                <div class="alert alert-warning">
+                    { format!("PNG images can be at most {} pixels square", MAX_PNG_SIZE) }
+                </div>
+            }
+        } else {
+            html! {}
+        }
+    }
+}
+
+impl Component for ExportControls {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            size: 800,
+            dpi: 300,
+            transparent: true,
+            colour: "#000000".to_string(),
+            paper: Paper::A4,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::SizeChange(size) => self.size = size.clamp(SIZES.0, SIZES.1),
+            Msg::DpiChange(dpi) => self.dpi = dpi.clamp(DPIS.0, DPIS.1),
+            Msg::ToggleTransparent => self.transparent = !self.transparent,
+            Msg::ColourChange(cd) => {
+                if let ChangeData::Value(colour) = cd {
+                    self.colour = colour;
+                }
+            }
+            Msg::PaperChange(i) => self.paper = Paper::ALL[i],
+            Msg::ExportSvg => {
+                self.props.on_export.emit(Format::Svg {
+                    size: u32::from(self.size),
+                });
+                return false;
+            }
+            Msg::ExportPng => {
+                self.props.on_export.emit(Format::Png {
+                    size: u32::from(self.size),
+                    dpi: f32::from(self.dpi),
+                    background: self.background(),
+                });
+                return false;
+            }
+            Msg::ExportPdf => {
+                self.props.on_export.emit(Format::Pdf { paper: self.paper });
+                return false;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let png = if cfg!(feature = "png") {
+            html! {
+                <button
+                    type="button"
+                    class="btn btn-secondary"
+                    disabled=self.too_large_for_png()
+                    onclick=self.link.callback(|_| Msg::ExportPng)
+                >
+                    { "Export PNG" }
+                </button>
+            }
+        } else {
+            html! {}
+        };
+        html! {
+            <div>
+                <div class="form-group">
+                    <label for="export-size">{ "Size in pixels" }</label>
+                    <IntegerInput
+                        id="export-size"
+                        value=self.size
+                        min=SIZES.0
+                        max=SIZES.1
+                        on_change=self.link.callback(Msg::SizeChange)
+                    />
+                    { self.size_warning() }
+                </div>
+                <div class="form-group">
+                    <label for="export-dpi">{ "Resolution of PNG images in DPI" }</label>
+                    <IntegerInput
+                        id="export-dpi"
+                        value=self.dpi
+                        min=DPIS.0
+                        max=DPIS.1
+                        on_change=self.link.callback(Msg::DpiChange)
+                    />
+                </div>
+                <div class="form-check">
+                    <input
+                        id="export-transparent"
+                        class="form-check-input"
+                        type="checkbox"
+                        checked=self.transparent
+                        onclick=self.link.callback(|_| Msg::ToggleTransparent)
+                    />
+                    <label class="form-check-label" for="export-transparent">
+                        { "Transparent background" }
+                    </label>
+                </div>
+                <div class="form-group">
+                    <label for="export-background">{ "Background colour" }</label>
+                    <input
+                        id="export-background"
+                        class="form-control"
+                        type="color"
+                        value=&self.colour
+                        disabled=self.transparent
+                        onchange=self.link.callback(Msg::ColourChange)
+                    />
+                </div>
+                <div class="form-group">
+                    <label for="export-paper">{ "Paper size of PDF sheets" }</label>
+                    <select
+                        id="export-paper"
+                        class="form-control"
+                        onchange=self.link.callback(|cd| {
+                            Msg::PaperChange(try_from_change_data(cd).unwrap_or(0))
+                        })
+                    >
+                        { for Paper::ALL.iter().enumerate().map(|(i, &paper)| html! {
+                            <option value=i selected=self.paper == paper>{ paper.name() }</option>
+                        }) }
+                    </select>
+                </div>
+                <button
+                    type="button"
+                    class="btn btn-secondary"
+                    onclick=self.link.callback(|_| Msg::ExportSvg)
+                >
+                    { "Export SVG" }
+                </button>
+                { png }
+                <button
+                    type="button"
+                    class="btn btn-secondary"
+                    onclick=self.link.callback(|_| Msg::ExportPdf)
+                >
+                    { "Export PDF" }
+                </button>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use yew::prelude::*;
+
+use super::drawing::HarmonicCycle;
+use super::SvgChart;
+use crate::app::{harmonics, try_from_change_data, Angles, Positions};
+use crate::aspect;
+use crate::glyphs::GlyphStyle;
+use crate::harmonic;
+use crate::layout::Layout;
+use crate::theme::Theme;
+
+const DEFAULT_LIST: &str = "1-16";
+const MAX_CHARTS: usize = 36;
+
+pub struct HarmonicGrid {
+    link: ComponentLink<Self>,
+    props: Props,
+    text: String,
+    harmonics: Vec<u16>,
+    error: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub positions: Positions,
+    pub aspect: bool,
+    pub aspect_types: aspect::Selection,
+    pub orb: aspect::Orb,
+    pub planets: u16,
+    pub angles: Angles,
+    pub glyph_style: GlyphStyle,
+    pub theme: Theme,
+    pub on_select: Callback<u16>,
+}
+
+pub enum Msg {
+    ListChange(String),
+    Select(u16),
+}
+
+impl Component for HarmonicGrid {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            text: DEFAULT_LIST.to_string(),
+            harmonics: parse_list(DEFAULT_LIST).unwrap(),
+            error: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ListChange(text) => {
+                match parse_list(&text) {
+                    Ok(harmonics) => {
+                        self.harmonics = harmonics;
+                        self.error = None;
+                    }
+                    Err(err) => self.error = Some(err),
+                }
+                self.text = text;
+            }
+            Msg::Select(harmonic) => {
+                self.props.on_select.emit(harmonic);
+                return false;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let on_list_change = self.link.callback(|cd| {
+            // Text inputs always produce a value, which always parses as a string
+            Msg::ListChange(try_from_change_data(cd).unwrap())
+        });
+        let maybe_error = match &self.error {
+            Some(err) => html! { <div class="alert alert-warning">{ err }</div> },
+            None => html! {},
+        };
+        html! {
+            <div>
+                <div class="form-group">
+                    <label for="grid-list">{ "Harmonics, e.g. 1-16 or 5,7,9,11,13" }</label>
+                    <input
+                        id="grid-list"
+                        class="form-control"
+                        type="text"
+                        value=self.text
+                        onchange=on_list_change
+                    />
+                    { maybe_error }
+                </div>
+                <div class="row no-gutters">
+                    { for self.harmonics.iter().map(|&h| self.cell(h)) }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl HarmonicGrid {
+    fn cell(&self, harmonic: u16) -> Html {
+        let harmonic_cycle = HarmonicCycle {
+            harmonic: f32::from(harmonic),
+            cycle: 0,
+            angles: self.props.angles,
+        };
+        html! {
+            <div class="col-3 text-center" onclick=self.link.callback(move |_| Msg::Select(harmonic))>
+                <SvgChart
+                    harmonic_cycle=harmonic_cycle
+                    positions=harmonics(&self.props.positions, f32::from(harmonic), self.props.angles)
+                    aspect=self.props.aspect
+                    aspect_types=self.props.aspect_types
+                    orb=self.props.orb.in_harmonic_chart(f32::from(harmonic))
+                    planets=self.props.planets
+                    layout=Layout::mini()
+                    glyph_style=self.props.glyph_style
+                    theme=&self.props.theme
+                />
+                <small>{ format!("H{}", harmonic) }</small>
+            </div>
+        }
+    }
+}
+
+/// Parse a comma separated list of harmonics and inclusive ranges, like `1-4,7,9`
+fn parse_list(text: &str) -> Result<Vec<u16>, String> {
+    let parse = |s: &str| -> Result<u16, String> {
+        match s.trim().parse() {
+            Ok(h) if (1..=harmonic::MAX_WHOLE_HARMONIC).contains(&h) => Ok(h),
+            Ok(h) => Err(format!(
+                "Harmonic {} is outside 1-{}",
+                h,
+                harmonic::MAX_WHOLE_HARMONIC
+            )),
+            Err(_) => Err(format!("Not a harmonic: {:?}", s.trim())),
+        }
+    };
+    let mut harmonics = Vec::new();
+    for item in text.split(',').filter(|item| !item.trim().is_empty()) {
+        let mut bounds = item.splitn(2, '-');
+        let start = parse(bounds.next().unwrap())?;
+        let end = match bounds.next() {
+            Some(end) => parse(end)?,
+            None => start,
+        };
+        if end < start {
+            return Err(format!("The range {:?} runs backwards", item.trim()));
+        }
+        harmonics.extend(start..=end);
+    }
+    if harmonics.len() > MAX_CHARTS {
+        Err(format!("At most {} charts can be shown", MAX_CHARTS))
+    } else {
+        Ok(harmonics)
+    }
+}
@@ -1,31 +1,65 @@
 use yew::prelude::*;
-use super::IntegerInput;
+
+use crate::app::try_from_change_data;
+use crate::harmonic;
 
 pub struct HarmonicSelect {
-    _link: ComponentLink<Self>,
+    link: ComponentLink<Self>,
     props: Props,
+    text: String,
+    error: Option<String>,
 }
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
-    pub harmonic: u16,
-    pub on_change: Callback<u16>,
+    pub harmonic: f32,
+    pub on_change: Callback<f32>,
+}
+
+pub enum Msg {
+    TextChange(String),
+    Step(f32),
 }
 
 impl Component for HarmonicSelect {
-    type Message = ();
+    type Message = Msg;
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { _link: link, props }
+        Self {
+            link,
+            text: harmonic::describe(props.harmonic),
+            props,
+            error: None,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        false
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::TextChange(text) => {
+                match harmonic::parse(&text) {
+                    Ok(harmonic) => {
+                        self.error = None;
+                        self.props.on_change.emit(harmonic);
+                    }
+                    Err(err) => self.error = Some(err),
+                }
+                self.text = text;
+            }
+            Msg::Step(step) => {
+                let harmonic = self.props.harmonic + step;
+                if harmonic > 0. && harmonic <= harmonic::MAX_HARMONIC {
+                    self.error = None;
+                    self.props.on_change.emit(harmonic);
+                }
+            }
+        }
+        true
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
+            self.text = harmonic::describe(props.harmonic);
             self.props = props;
             true
         } else {
@@ -34,16 +68,37 @@ impl Component for HarmonicSelect {
     }
 
     fn view(&self) -> Html {
+        let on_text_change = self.link.callback(|cd| {
+            // Text inputs always produce a value, which always parses as a string
+            Msg::TextChange(try_from_change_data(cd).unwrap())
+        });
+        let maybe_error = match &self.error {
+            Some(err) => html! { <div class="alert alert-warning">{ err }</div> },
+            None => html! {},
+        };
         html! {
             <div class="form-group">
-                <label for="harmonic-select">{ "Harmonic" }</label>
-                <IntegerInput
-                    id="harmonic-select"
-                    value=self.props.harmonic
-                    min=1
-                    max=300
-                    on_change=self.props.on_change.clone()
-                />
+                <label for="harmonic-select">{ "Harmonic, e.g. 7, 1.5 or 3/2" }</label>
+                <div class="input-group">
+                    <div class="input-group-prepend">
+                        <button class="btn btn-secondary" onclick=self.link.callback(|_| Msg::Step(-1.))>
+                            { '−' }
+                        </button>
+                    </div>
+                    <input
+                        id="harmonic-select"
+                        class="form-control"
+                        type="text"
+                        value=self.text
+                        onchange=on_text_change
+                    />
+                    <div class="input-group-append">
+                        <button class="btn btn-secondary" onclick=self.link.callback(|_| Msg::Step(1.))>
+                            { '+' }
+                        </button>
+                    </div>
+                </div>
+                { maybe_error }
             </div>
         }
     }
@@ -0,0 +1,175 @@
+use yew::prelude::*;
+
+use super::IntegerInput;
+use crate::app::Positions;
+use crate::harmonic::{self, Score};
+
+const WIDTH: f32 = 300.;
+const HEIGHT: f32 = 100.;
+
+pub struct HarmonicSpectrum {
+    link: ComponentLink<Self>,
+    props: Props,
+    max_harmonic: u16,
+    score: Score,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub positions: Positions,
+    pub planets: u16,
+    /// The harmonic currently drawn, if it is a whole number
+    pub harmonic: Option<u16>,
+    pub on_select: Callback<u16>,
+}
+
+pub enum Msg {
+    MaxHarmonicChange(u16),
+    ScoreChange(Score),
+    Select(u16),
+}
+
+impl Component for HarmonicSpectrum {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            max_harmonic: 32,
+            score: Score::Resultant,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::MaxHarmonicChange(max_harmonic) => {
+                self.max_harmonic = max_harmonic.clamp(1, harmonic::MAX_WHOLE_HARMONIC)
+            }
+            Msg::ScoreChange(score) => self.score = score,
+            Msg::Select(harmonic) => {
+                self.props.on_select.emit(harmonic);
+                return false;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let on_max_change = self.link.callback(Msg::MaxHarmonicChange);
+        let score_button = |score: Score| {
+            let class = if score == self.score {
+                "btn btn-secondary active"
+            } else {
+                "btn btn-secondary"
+            };
+            html! {
+                <button class=class onclick=self.link.callback(move |_| Msg::ScoreChange(score))>
+                    { score.name() }
+                </button>
+            }
+        };
+        html! {
+            <div>
+                <div class="form-group">
+                    <label for="spectrum-max">{ "Highest harmonic" }</label>
+                    <IntegerInput
+                        id="spectrum-max"
+                        value=self.max_harmonic
+                        min=1
+                        max=harmonic::MAX_WHOLE_HARMONIC
+                        on_change=on_max_change
+                    />
+                </div>
+                <div class="btn-group btn-group-sm" role="group">
+                    { score_button(Score::Resultant) }
+                    { score_button(Score::Conjunctions) }
+                </div>
+                { self.plot() }
+            </div>
+        }
+    }
+}
+
+impl HarmonicSpectrum {
+    fn plot(&self) -> Html {
+        let angles: Vec<f32> = self
+            .props
+            .positions
+            .planets_without_node()
+            .iter()
+            .take(self.props.planets as usize + 2)
+            .copied()
+            .collect();
+        let scores = harmonic::spectrum(&angles, self.max_harmonic, self.score);
+        let max_score = scores.iter().copied().fold(0., f32::max);
+        let scale = if max_score > 0. {
+            HEIGHT / max_score
+        } else {
+            0.
+        };
+        let bar_width = WIDTH / f32::from(self.max_harmonic);
+        let bar = |(i, score): (usize, &f32)| {
+            let harmonic = i as u16 + 1;
+            let height = score * scale;
+            let fill = if Some(harmonic) == self.props.harmonic {
+                "#aa0000"
+            } else {
+                "#0000aa"
+            };
+            html! {
+                <rect
+                    x=bar_width * f32::from(harmonic - 1)
+                    y=HEIGHT - height
+                    width=bar_width
+                    height=height
+                    fill=fill
+                    stroke="white"
+                    stroke-width=(bar_width * 0.1).min(0.5)
+                    onclick=self.link.callback(move |_| Msg::Select(harmonic))
+                >
+                    <title>{ format!("H{}: {:.3}", harmonic, score) }</title>
+                </rect>
+            }
+        };
+        let tick_step = match self.max_harmonic {
+            0..=20 => 1,
+            21..=60 => 5,
+            61..=150 => 10,
+            _ => 50,
+        };
+        let tick = |harmonic: u16| {
+            let x = bar_width * (f32::from(harmonic) - 0.5);
+            html! {
+                <text x=x y=HEIGHT + 8. font-size="6" text-anchor="middle">
+                    { harmonic }
+                </text>
+            }
+        };
+        html! {
+            <svg class="img-fluid"
+                version="1.1" baseProfile="full"
+                xmlns="http://www.w3.org/2000/svg"
+                viewBox=format!("0 0 {} {}", WIDTH, HEIGHT + 10.)
+            >
+                <rect width=WIDTH height=HEIGHT fill="#55ffff" />
+                { for scores.iter().enumerate().map(bar) }
+                {
+                    for (1..=self.max_harmonic)
+                        .filter(|h| h % tick_step == 0 || *h == 1)
+                        .map(tick)
+                }
+            </svg>
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+use yew::prelude::*;
+use yew::services::interval::{IntervalService, IntervalTask};
+
+use super::IntegerInput;
+use crate::harmonic;
+
+const FRAME: Duration = Duration::from_millis(40);
+/// The speeds on offer, in harmonics per second
+const SPEEDS: [f32; 5] = [0.1, 0.25, 0.5, 1., 2.];
+
+/// Playback controls that sweep the harmonic continuously from 1 up to an end harmonic
+pub struct HarmonicSweep {
+    link: ComponentLink<Self>,
+    props: Props,
+    end: u16,
+    speed: f32,
+    looping: bool,
+    playing: Option<IntervalTask>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub harmonic: f32,
+    pub on_change: Callback<f32>,
+}
+
+pub enum Msg {
+    TogglePlay,
+    Frame,
+    EndChange(u16),
+    SpeedChange(f32),
+    ToggleLoop,
+}
+
+impl Component for HarmonicSweep {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            end: 32,
+            speed: 0.25,
+            looping: false,
+            playing: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::TogglePlay => {
+                if self.playing.is_some() {
+                    self.playing = None;
+                } else {
+                    if self.props.harmonic >= f32::from(self.end) {
+                        self.props.on_change.emit(1.);
+                    }
+                    let frame = self.link.callback(|()| Msg::Frame);
+                    self.playing = Some(IntervalService::new().spawn(FRAME, frame));
+                }
+            }
+            Msg::Frame => {
+                let end = f32::from(self.end);
+                let mut harmonic = self.props.harmonic + self.speed * FRAME.as_secs_f32();
+                if harmonic > end {
+                    if self.looping {
+                        harmonic = 1.;
+                    } else {
+                        harmonic = end;
+                        self.playing = None;
+                    }
+                }
+                self.props.on_change.emit(harmonic);
+                return self.playing.is_none();
+            }
+            Msg::EndChange(end) => self.end = end.clamp(2, harmonic::MAX_WHOLE_HARMONIC),
+            Msg::SpeedChange(speed) => self.speed = speed,
+            Msg::ToggleLoop => self.looping = !self.looping,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let speed_button = |speed: f32| {
+            let class = if (speed - self.speed).abs() < std::f32::EPSILON {
+                "btn btn-secondary active"
+            } else {
+                "btn btn-secondary"
+            };
+            html! {
+                <button class=class onclick=self.link.callback(move |_| Msg::SpeedChange(speed))>
+                    { format!("{}×", speed) }
+                </button>
+            }
+        };
+        html! {
+            <div class="form-group">
+                <label for="sweep-end">{ "Sweep up to harmonic" }</label>
+                <IntegerInput
+                    id="sweep-end"
+                    value=self.end
+                    min=2
+                    max=harmonic::MAX_WHOLE_HARMONIC
+                    on_change=self.link.callback(Msg::EndChange)
+                />
+                <div class="btn-toolbar" role="toolbar">
+                    <div class="btn-group btn-group-sm mr-2" role="group">
+                        <button class="btn btn-primary" onclick=self.link.callback(|_| Msg::TogglePlay)>
+                            { if self.playing.is_some() { "Pause" } else { "Play" } }
+                        </button>
+                    </div>
+                    <div class="btn-group btn-group-sm mr-2" role="group">
+                        { for SPEEDS.iter().copied().map(speed_button) }
+                    </div>
+                    <div class="form-check form-check-inline">
+                        <input
+                            id="sweep-loop"
+                            class="form-check-input"
+                            type="checkbox"
+                            checked=self.looping
+                            onchange=self.link.callback(|_| Msg::ToggleLoop)
+                        />
+                        <label for="sweep-loop" class="form-check-label">{ "Loop" }</label>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
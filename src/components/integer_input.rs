@@ -1,11 +1,10 @@
 use crate::app::try_from_change_data;
-use std::num::ParseIntError;
 use yew::prelude::*;
 
 pub struct IntegerInput {
     link: ComponentLink<Self>,
     props: Props,
-    error: Option<ParseIntError>,
+    error: Option<String>,
 }
 
 #[derive(Clone, PartialEq, Properties)]
@@ -34,11 +33,17 @@ impl Component for IntegerInput {
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg(cd) => match try_from_change_data::<u16>(cd) {
-                Ok(v) => {
+                Ok(v) if (self.props.min..=self.props.max).contains(&v) => {
                     self.error = None;
                     self.props.on_change.emit(v);
                 }
-                Err(detail) => self.error = detail.into(),
+                Ok(_) => {
+                    self.error = Some(format!(
+                        "Enter a number from {} to {}",
+                        self.props.min, self.props.max
+                    ));
+                }
+                Err(detail) => self.error = Some(detail.to_string()),
             },
         };
         true
@@ -55,7 +60,7 @@ impl Component for IntegerInput {
 
     fn view(&self) -> Html {
         let maybe_error = match &self.error {
-            Some(err) => html! { <div class="alert alert-warning">{ err.to_string() }</div> },
+            Some(err) => html! { <div class="alert alert-warning">{ err }</div> },
             None => html! {},
         };
         html! {
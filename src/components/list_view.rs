@@ -1,19 +1,17 @@
-use crate::app::Positions;
+use crate::antiscia::Reflection;
+use crate::app::{truncate_angle, Positions, GLYPHS};
+use crate::lots::LotPosition;
 use yew::prelude::*;
 
-lazy_static! {
-    static ref NAMES: Vec<&'static str> = vec![
-        "\u{2609}", "\u{263d}", "\u{263f}", "\u{2640}", "\u{2642}", "\u{2643}", "\u{2644}",
-        "\u{2645}", "\u{2646}", "\u{2647}", "\u{260a}", "MC", "ASC",
-    ];
-}
-
 const NUM: usize = 13;
 
 pub struct ListView {
     link: ComponentLink<Self>,
     positions: Positions,
+    lots: Vec<LotPosition>,
     angle_format: AngleFormat,
+    /// Show the antiscion and contra-antiscion of each point
+    reflections: bool,
     offset: usize,
 }
 
@@ -22,18 +20,16 @@ enum AngleFormat {
     Full,
 }
 
-lazy_static! {
-    static ref ZODIAC_SIGNS: Vec<&'static str> =
-        vec!["Ari", "Tau", "Gem", "Can", "Leo", "Vir", "Lib", "Sco", "Sag", "Cap", "Aqu", "Psc",];
-}
-
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
     pub positions: Positions,
+    #[prop_or_default]
+    pub lots: Vec<LotPosition>,
 }
 
 pub enum Msg {
     Toggle,
+    ToggleReflections,
     IncOffset,
     DecOffset,
 }
@@ -46,7 +42,9 @@ impl Component for ListView {
         Self {
             link,
             positions: props.positions,
+            lots: props.lots,
             angle_format: AngleFormat::Truncated,
+            reflections: false,
             offset: 0,
         }
     }
@@ -57,6 +55,7 @@ impl Component for ListView {
                 AngleFormat::Full => self.angle_format = AngleFormat::Truncated,
                 AngleFormat::Truncated => self.angle_format = AngleFormat::Full,
             },
+            Msg::ToggleReflections => self.reflections = !self.reflections,
             Msg::IncOffset => self.offset = (self.offset + NUM - 1) % NUM,
             Msg::DecOffset => self.offset = (self.offset + 1) % NUM,
         };
@@ -65,27 +64,44 @@ impl Component for ListView {
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         self.positions = props.positions;
+        self.lots = props.lots;
         true
     }
 
     fn view(&self) -> Html {
         let on_toggle = self.link.callback(|_| Msg::Toggle);
+        let on_toggle_reflections = self.link.callback(|_| Msg::ToggleReflections);
         let on_scrollup = self.link.callback(|_| Msg::DecOffset);
         let on_scrolldown = self.link.callback(|_| Msg::IncOffset);
 
         let indices = (self.offset..NUM).chain(0..self.offset);
         let pos_vec = self.positions.0.to_vec();
+        let head = if self.reflections {
+            html! {
+                <tr>
+                    <th></th>
+                    <th></th>
+                    { for Reflection::ALL.iter().map(|r| html! { <th>{ r.name() }</th> }) }
+                </tr>
+            }
+        } else {
+            html! {}
+        };
         html! {
             <div>
                 <table class="table table-sm table-hover">
-                    <thead></thead>
+                    <thead>{ head }</thead>
                     <tbody>
-                        { for indices.map(|i| self.format_row(NAMES[i], pos_vec[i])) }
+                        { for indices.map(|i| self.format_row(GLYPHS[i], pos_vec[i])) }
+                        { for self.lots.iter().map(|lot| self.format_row(&lot.symbol, lot.position)) }
                     </tbody>
                 </table>
                 <div class="btn-group d-flex" role="group">
                     <button class="btn btn-secondary w-50" onclick=on_scrollup>{ '▲' }</button>
                     <button class="btn btn-secondary w-100" onclick=on_toggle >{ "Toggle" }</button>
+                    <button class="btn btn-secondary w-100" onclick=on_toggle_reflections>
+                        { "Antiscia" }
+                    </button>
                     <button class="btn btn-secondary w-50" onclick=on_scrolldown>{ '▼' }</button>
                 </div>
             </div>
@@ -95,31 +111,30 @@ impl Component for ListView {
 
 impl ListView {
     fn format_row(&self, name: &str, angle: f32) -> Html {
-        let text = match self.angle_format {
-            AngleFormat::Truncated => truncate_angle(angle),
-            AngleFormat::Full => full_angle(angle),
+        let reflections = if self.reflections {
+            html! {
+                { for Reflection::ALL.iter().map(|r| html! {
+                    <td>{ self.format_angle(r.reflect(angle)) }</td>
+                }) }
+            }
+        } else {
+            html! {}
         };
         html! {
             <tr>
                 <td>{ name }</td>
-                <td>{ text }</td>
+                <td>{ self.format_angle(angle) }</td>
+                { reflections }
             </tr>
         }
     }
-}
 
-fn truncate_angle(angle: f32) -> String {
-    assert!(angle >= 0.);
-    let mut int_part = angle.floor();
-    let decimal_part: f32 = angle - int_part;
-    let zodiac_sign = ZODIAC_SIGNS[int_part as usize / 30];
-    let mut i = (decimal_part * 60.).round();
-    int_part %= 30.;
-    if i > 59. {
-        i -= 60.;
-        int_part += 1.;
+    fn format_angle(&self, angle: f32) -> String {
+        match self.angle_format {
+            AngleFormat::Truncated => truncate_angle(angle),
+            AngleFormat::Full => full_angle(angle),
+        }
     }
-    format!("{:02}° {:02}' {}", int_part, i, zodiac_sign)
 }
 
 fn full_angle(angle: f32) -> String {
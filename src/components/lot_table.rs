@@ -0,0 +1,237 @@
+use yew::prelude::*;
+
+use crate::app::{try_from_change_data, Positions, NAMES};
+use crate::lots::{self, Lot, Operand};
+
+/// The table of lots in the chart, with a form to define new ones
+pub struct LotTable {
+    link: ComponentLink<Self>,
+    props: Props,
+    name: String,
+    add: usize,
+    subtract: usize,
+    reverses: bool,
+    error: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub positions: Positions,
+    pub lots: Vec<Lot>,
+    pub on_change: Callback<Vec<Lot>>,
+}
+
+pub enum Msg {
+    NameInput(String),
+    AddChange(usize),
+    SubtractChange(usize),
+    ToggleReverses,
+    Add,
+    Remove(usize),
+    Noop,
+}
+
+impl Component for LotTable {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            name: String::new(),
+            add: 0,
+            subtract: 0,
+            reverses: true,
+            error: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::NameInput(name) => self.name = name,
+            Msg::AddChange(add) => self.add = add,
+            Msg::SubtractChange(subtract) => self.subtract = subtract,
+            Msg::ToggleReverses => self.reverses = !self.reverses,
+            Msg::Add => {
+                let name = self.name.trim();
+                if name.is_empty() {
+                    self.error = Some("The lot needs a name".to_string());
+                } else if self.props.lots.iter().any(|lot| lot.name == name) {
+                    self.error = Some(format!("There is already a lot called {}", name));
+                } else if self.add == self.subtract {
+                    self.error = Some("The two terms of the formula are the same".to_string());
+                } else if self.add.max(self.subtract) >= NAMES.len() + self.props.lots.len() {
+                    self.error =
+                        Some("A term of the formula is no longer in the table".to_string());
+                } else {
+                    let mut lots = self.props.lots.clone();
+                    lots.push(Lot::new(
+                        name,
+                        operand(self.add),
+                        operand(self.subtract),
+                        self.reverses,
+                    ));
+                    self.error = None;
+                    self.name.clear();
+                    self.props.on_change.emit(lots);
+                }
+            }
+            Msg::Remove(i) => match lots::remove(&self.props.lots, i) {
+                Ok(lots) => {
+                    self.error = None;
+                    self.props.on_change.emit(lots);
+                }
+                Err(err) => self.error = Some(err),
+            },
+            Msg::Noop => return false,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            // Removing a lot shifts those after it, so a chosen lot may now be another one
+            if self.props.lots != props.lots {
+                if self.add >= NAMES.len() {
+                    self.add = 0;
+                }
+                if self.subtract >= NAMES.len() {
+                    self.subtract = 0;
+                }
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let lots = &self.props.lots;
+        let placed = lots::positions(lots, &self.props.positions);
+        let referenced = |i: usize| {
+            lots.iter()
+                .any(|lot| lot.add == Operand::Lot(i) || lot.subtract == Operand::Lot(i))
+        };
+        let row = |(i, lot): (usize, &Lot)| {
+            // The standard lots and those others are built on stay put
+            let remove = if i < lots::STANDARD || referenced(i) {
+                html! {}
+            } else {
+                html! {
+                    <button
+                        class="btn btn-sm btn-secondary"
+                        onclick=self.link.callback(move |_| Msg::Remove(i))
+                    >
+                        { "Remove" }
+                    </button>
+                }
+            };
+            html! {
+                <tr>
+                    <td>{ &lot.symbol }</td>
+                    <td>{ &lot.name }</td>
+                    <td>{ lot.formula(lots) }</td>
+                    <td>
+                        {
+                            match placed.get(i) {
+                                Some(lot) => format!("{:.2}°", lot.position),
+                                None => "—".to_string(),
+                            }
+                        }
+                    </td>
+                    <td>{ remove }</td>
+                </tr>
+            }
+        };
+        let maybe_error = match &self.error {
+            Some(err) => html! { <div class="alert alert-warning">{ err }</div> },
+            None => html! {},
+        };
+        let chart = if lots::is_day(&self.props.positions) {
+            "Day chart: the Sun is above the horizon"
+        } else {
+            "Night chart: the Sun is below the horizon"
+        };
+        html! {
+            <div>
+                <p>{ chart }</p>
+                <table class="table table-sm table-hover">
+                    <tbody>{ for lots.iter().enumerate().map(row) }</tbody>
+                </table>
+                { self.form() }
+                { maybe_error }
+            </div>
+        }
+    }
+}
+
+impl LotTable {
+    /// The form to define a new lot
+    fn form(&self) -> Html {
+        let options = |selected: usize| {
+            NAMES
+                .iter()
+                .copied()
+                .chain(self.props.lots.iter().map(|lot| lot.name.as_str()))
+                .enumerate()
+                .map(|(i, name)| html! { <option value=i selected=i == selected>{ name }</option> })
+                .collect::<Html>()
+        };
+        html! {
+        <div class="form-inline">
+            <input
+                class="form-control mr-1"
+                placeholder="Name"
+                value=&self.name
+                oninput=self.link.callback(|e: InputData| Msg::NameInput(e.value))
+            />
+            { "ASC +" }
+            <select
+                class="form-control mx-1"
+                onchange=self.link.callback(|cd| match try_from_change_data(cd) {
+                    Ok(i) => Msg::AddChange(i),
+                    Err(_) => Msg::Noop,
+                })
+            >
+                { options(self.add) }
+            </select>
+            { "−" }
+            <select
+                class="form-control mx-1"
+                onchange=self.link.callback(|cd| match try_from_change_data(cd) {
+                    Ok(i) => Msg::SubtractChange(i),
+                    Err(_) => Msg::Noop,
+                })
+            >
+                { options(self.subtract) }
+            </select>
+            <div class="form-check mx-1">
+                <input
+                    id="lot-reverses"
+                    class="form-check-input"
+                    type="checkbox"
+                    checked=self.reverses
+                    onchange=self.link.callback(|_| Msg::ToggleReverses)
+                />
+                <label for="lot-reverses" class="form-check-label">
+                    { "Reverse at night" }
+                </label>
+            </div>
+            <button class="btn btn-secondary" onclick=self.link.callback(|_| Msg::Add)>
+                { "Add lot" }
+            </button>
+        </div>
+        }
+    }
+}
+
+/// The operand for an index into the options, which list the points before the lots
+fn operand(i: usize) -> Operand {
+    if i < NAMES.len() {
+        Operand::Point(i)
+    } else {
+        Operand::Lot(i - NAMES.len())
+    }
+}
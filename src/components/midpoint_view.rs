@@ -0,0 +1,308 @@
+use yew::prelude::*;
+
+use crate::app::{try_from_change_data, Positions, GLYPHS};
+use crate::midpoint::{self, Factor, MODULI};
+use crate::placement::{self, Label};
+
+/// The number of ticks around the dial, whatever the modulus
+const TICKS: usize = 90;
+const DIAL_RADIUS: f32 = 100.;
+const MARKER_RADIUS: f32 = 70.;
+const GLYPH_RADIUS: f32 = 82.;
+
+/// Midpoint lists and a dial with a pointer that can be dragged round to read off pictures
+pub struct MidpointView {
+    link: ComponentLink<Self>,
+    props: Props,
+    modulus: f32,
+    orb: f32,
+    error: Option<String>,
+    pointer: f32,
+    dragging: bool,
+    dial: NodeRef,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub positions: Positions,
+}
+
+pub enum Msg {
+    ModulusChange(f32),
+    OrbChange(Result<f32, std::num::ParseFloatError>),
+    DragStart(MouseEvent),
+    Drag(MouseEvent),
+    DragEnd,
+}
+
+impl Component for MidpointView {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            modulus: 90.,
+            orb: 1.5,
+            error: None,
+            pointer: 0.,
+            dragging: false,
+            dial: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ModulusChange(modulus) => {
+                self.pointer %= modulus;
+                self.modulus = modulus;
+            }
+            Msg::OrbChange(Ok(orb)) if orb.is_finite() && orb > 0. => {
+                self.error = None;
+                self.orb = orb;
+            }
+            Msg::OrbChange(Ok(_)) => {
+                self.error = Some("The orb must be a number of degrees above 0".to_string());
+            }
+            Msg::OrbChange(Err(err)) => self.error = Some(err.to_string()),
+            Msg::DragStart(event) => {
+                self.dragging = true;
+                self.move_pointer(&event);
+            }
+            Msg::Drag(event) => {
+                if !self.dragging {
+                    return false;
+                }
+                self.move_pointer(&event);
+            }
+            Msg::DragEnd => self.dragging = false,
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let modulus_button = |modulus: f32| {
+            let class = if (modulus - self.modulus).abs() < std::f32::EPSILON {
+                "btn btn-secondary active"
+            } else {
+                "btn btn-secondary"
+            };
+            html! {
+                <button class=class onclick=self.link.callback(move |_| Msg::ModulusChange(modulus))>
+                    { format!("{}°", modulus) }
+                </button>
+            }
+        };
+        let on_orb_change = self
+            .link
+            .callback(|cd| Msg::OrbChange(try_from_change_data(cd)));
+        let maybe_error = match &self.error {
+            Some(err) => html! { <div class="alert alert-warning">{ err }</div> },
+            None => html! {},
+        };
+        html! {
+            <div class="row">
+                <div class="col-md-5">
+                    <div class="btn-group btn-group-sm" role="group">
+                        { for MODULI.iter().copied().map(modulus_button) }
+                    </div>
+                    { self.dial() }
+                    { self.pointer_readout() }
+                </div>
+                <div class="col">
+                    <div class="form-group">
+                        <label for="midpoint-orb">{ "Orb" }</label>
+                        <input
+                            id="midpoint-orb"
+                            class="form-control"
+                            type="number"
+                            step="0.1"
+                            min="0.1"
+                            value=self.orb
+                            onchange=on_orb_change
+                        />
+                        { maybe_error }
+                    </div>
+                    <h5>{ "Occupied midpoints" }</h5>
+                    { self.pictures() }
+                </div>
+                <div class="col">
+                    <h5>{ format!("Sorted by {}°", self.modulus) }</h5>
+                    { self.sorted_list() }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl MidpointView {
+    fn points(&self) -> &[f32] {
+        &self.props.positions.0
+    }
+
+    /// Degrees of the dial per degree of the modulus
+    fn scale(&self) -> f32 {
+        360. / self.modulus
+    }
+
+    /// Point the pointer at the mouse
+    fn move_pointer(&mut self, event: &MouseEvent) {
+        let element = match self.dial.cast::<web_sys::Element>() {
+            Some(element) => element,
+            None => return,
+        };
+        let rect = element.get_bounding_client_rect();
+        let dx = f64::from(event.client_x()) - (rect.left() + rect.width() / 2.);
+        let dy = f64::from(event.client_y()) - (rect.top() + rect.height() / 2.);
+        // The dial starts at the top and runs counter-clockwise
+        let angle = (-dx).atan2(-dy).to_degrees().rem_euclid(360.) as f32;
+        self.pointer = angle / self.scale();
+    }
+
+    fn dial(&self) -> Html {
+        let scale = self.scale();
+        let point = |radius: f32, angle: f32| {
+            let angle = angle.to_radians();
+            (-radius * angle.sin(), -radius * angle.cos())
+        };
+        let tick = |i: usize| {
+            let angle = 360. * i as f32 / TICKS as f32;
+            let length = if i % 5 == 0 { 6. } else { 3. };
+            let (x0, y0) = point(DIAL_RADIUS, angle);
+            let (x1, y1) = point(DIAL_RADIUS - length, angle);
+            let label = if i % 10 == 0 {
+                let (x, y) = point(DIAL_RADIUS + 5., angle);
+                html! {
+                    <text x=x y=y font-size="5" text-anchor="middle" dominant-baseline="middle">
+                        { format_position(angle / scale) }
+                    </text>
+                }
+            } else {
+                html! {}
+            };
+            html! {
+                <>
+                    <path d=format!("M {} {} L {} {}", x0, y0, x1, y1) stroke="black" />
+                    { label }
+                </>
+            }
+        };
+        let angles: Vec<f32> = self
+            .points()
+            .iter()
+            .map(|p| p.rem_euclid(self.modulus) * scale)
+            .collect();
+        // The glyphs are 8 high, with a little room between them
+        let labels: Vec<Label> = angles
+            .iter()
+            .map(|&target| Label {
+                target,
+                width: placement::width(10., GLYPH_RADIUS),
+                track: 0,
+            })
+            .collect();
+        let glyph_angles = placement::place(&labels);
+        let marker = |(i, &angle): (usize, &f32)| {
+            let (x, y) = point(MARKER_RADIUS, angle);
+            let (gx, gy) = point(GLYPH_RADIUS, glyph_angles[i]);
+            html! {
+                <>
+                    <circle cx=x cy=y r=1.5 fill="white" stroke="black" stroke-width="0.5" />
+                    <text x=gx y=gy font-size="8" text-anchor="middle" dominant-baseline="middle">
+                        { GLYPHS[i] }
+                    </text>
+                </>
+            }
+        };
+        let pointer_angle = self.pointer * scale;
+        let (px, py) = point(DIAL_RADIUS, pointer_angle);
+        let (ox, oy) = point(DIAL_RADIUS, pointer_angle + 180.);
+        html! {
+            <svg class="img-fluid"
+                version="1.1" baseProfile="full"
+                xmlns="http://www.w3.org/2000/svg"
+                viewBox="-112 -112 224 224"
+                ref=self.dial.clone()
+                onmousedown=self.link.callback(Msg::DragStart)
+                onmousemove=self.link.callback(Msg::Drag)
+                onmouseup=self.link.callback(|_| Msg::DragEnd)
+                onmouseleave=self.link.callback(|_| Msg::DragEnd)
+            >
+                <circle r=DIAL_RADIUS fill="#55ffff" stroke="black" stroke-width="0.5" />
+                <circle r=MARKER_RADIUS - 5. fill="#5555ff" stroke="white" />
+                { for (0..TICKS).map(tick) }
+                { for angles.iter().enumerate().map(marker) }
+                <path d=format!("M {} {} L 0 0", ox, oy) stroke="#aa0000" stroke-dasharray="3 2" />
+                <path d=format!("M 0 0 L {} {}", px, py) stroke="#aa0000" stroke-width="1.5" />
+                <circle r=3 fill="#aa0000" />
+            </svg>
+        }
+    }
+
+    fn pointer_readout(&self) -> Html {
+        let factors = midpoint::at(self.points(), self.pointer, self.modulus, self.orb);
+        html! {
+            <p>
+                { format!("Pointer at {}: ", format_position(self.pointer)) }
+                { factors.iter().map(|&f| format_factor(f)).collect::<Vec<_>>().join(", ") }
+            </p>
+        }
+    }
+
+    fn pictures(&self) -> Html {
+        let pictures = midpoint::pictures(self.points(), self.modulus, self.orb);
+        html! {
+            <table class="table table-sm">
+                <tbody>
+                    { for pictures.iter().map(|p| html! {
+                        <tr>
+                            <td>{ format!("{} = {}/{}", GLYPHS[p.point], GLYPHS[p.first], GLYPHS[p.second]) }</td>
+                            <td>{ format_position(p.orb) }</td>
+                        </tr>
+                    }) }
+                </tbody>
+            </table>
+        }
+    }
+
+    fn sorted_list(&self) -> Html {
+        let entries = midpoint::sorted(self.points(), self.modulus);
+        html! {
+            <div style="max-height: 30em; overflow-y: auto">
+                <table class="table table-sm">
+                    <tbody>
+                        { for entries.iter().map(|e| html! {
+                            <tr>
+                                <td>{ format_position(e.position) }</td>
+                                <td>{ format_factor(e.factor) }</td>
+                            </tr>
+                        }) }
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+}
+
+fn format_factor(factor: Factor) -> String {
+    match factor {
+        Factor::Point(i) => GLYPHS[i].to_string(),
+        Factor::Midpoint(i, j) => format!("{}/{}", GLYPHS[i], GLYPHS[j]),
+    }
+}
+
+/// Degrees and minutes, without a sign since the position may be reduced to a modulus
+fn format_position(position: f32) -> String {
+    let minutes = (position * 60.).round() as u32;
+    format!("{}°{:02}'", minutes / 60, minutes % 60)
+}
@@ -1,21 +1,51 @@
+mod age_harmonic;
+mod aspect_list;
+mod aspect_select;
 mod bottom_bar;
+mod conjunction_finder;
 mod cycle_select;
+mod dignity_table;
+mod dispositor_graph;
 mod drawing;
+mod export_controls;
+mod harmonic_grid;
 mod harmonic_select;
+mod harmonic_spectrum;
+mod harmonic_sweep;
 mod integer_input;
 mod list_view;
+mod lot_table;
+mod midpoint_view;
+mod orb_select;
 mod planet_select;
+mod shape_summary;
 mod svg_chart;
 mod text_input;
+mod theme_select;
 mod top_bar;
 
+pub use age_harmonic::AgeHarmonic;
+pub use aspect_list::AspectList;
+pub use aspect_select::AspectSelect;
 pub use bottom_bar::BottomBar;
+pub use conjunction_finder::ConjunctionFinder;
 pub use cycle_select::CycleSelect;
+pub use dignity_table::DignityTable;
+pub use dispositor_graph::DispositorGraph;
 pub use drawing::Drawing;
+pub use export_controls::ExportControls;
+pub use harmonic_grid::HarmonicGrid;
 pub use harmonic_select::HarmonicSelect;
+pub use harmonic_spectrum::HarmonicSpectrum;
+pub use harmonic_sweep::HarmonicSweep;
 pub use integer_input::IntegerInput;
 pub use list_view::ListView;
+pub use lot_table::LotTable;
+pub use midpoint_view::MidpointView;
+pub use orb_select::OrbSelect;
 pub use planet_select::PlanetSelect;
+pub use shape_summary::ShapeSummary;
 pub use svg_chart::SvgChart;
 pub use text_input::TextInput;
+pub use theme_select::ThemeSelect;
 pub use top_bar::TopBar;
@@ -0,0 +1,114 @@
+use yew::prelude::*;
+
+use crate::app::try_from_change_data;
+use crate::aspect::{Orb, OrbBasis};
+
+pub struct OrbSelect {
+    link: ComponentLink<Self>,
+    props: Props,
+    error: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub orb: Orb,
+    /// The harmonic currently drawn, used to show the orb in the other chart
+    pub harmonic: f32,
+    pub on_change: Callback<Orb>,
+}
+
+pub enum Msg {
+    DegreesChange(Result<f32, std::num::ParseFloatError>),
+    BasisChange(OrbBasis),
+}
+
+impl Component for OrbSelect {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            error: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::DegreesChange(Ok(degrees)) if degrees > 0. && degrees <= 15. => {
+                self.error = None;
+                self.props.on_change.emit(Orb {
+                    degrees,
+                    ..self.props.orb
+                });
+            }
+            Msg::DegreesChange(Ok(_)) => {
+                self.error = Some("The orb must be above 0° and at most 15°".to_string())
+            }
+            Msg::DegreesChange(Err(err)) => self.error = Some(err.to_string()),
+            Msg::BasisChange(basis) => self.props.on_change.emit(Orb {
+                basis,
+                ..self.props.orb
+            }),
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let on_degrees_change = self
+            .link
+            .callback(|cd| Msg::DegreesChange(try_from_change_data(cd)));
+        let basis_button = |basis: OrbBasis, name: &str| {
+            let class = if basis == self.props.orb.basis {
+                "btn btn-secondary active"
+            } else {
+                "btn btn-secondary"
+            };
+            html! {
+                <button class=class onclick=self.link.callback(move |_| Msg::BasisChange(basis))>
+                    { name }
+                </button>
+            }
+        };
+        let maybe_error = match &self.error {
+            Some(err) => html! { <div class="alert alert-warning">{ err }</div> },
+            None => html! {},
+        };
+        let harmonic = self.props.harmonic;
+        html! {
+            <div class="form-group">
+                <label for="orb-select">{ "Orb in degrees, measured in the" }</label>
+                <div class="input-group">
+                    <input
+                        id="orb-select"
+                        class="form-control"
+                        type="number"
+                        step="any"
+                        value=self.props.orb.degrees
+                        onchange=on_degrees_change
+                    />
+                    <div class="input-group-append">
+                        { basis_button(OrbBasis::HarmonicChart, "Harmonic chart") }
+                        { basis_button(OrbBasis::Radix, "Radix") }
+                    </div>
+                </div>
+                { maybe_error }
+                <small class="form-text text-muted">{ format!(
+                    "{:.2}° in the harmonic chart, {:.2}° in the radix",
+                    self.props.orb.in_harmonic_chart(harmonic),
+                    self.props.orb.in_radix(harmonic),
+                ) }</small>
+            </div>
+        }
+    }
+}
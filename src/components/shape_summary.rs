@@ -0,0 +1,77 @@
+use yew::prelude::*;
+
+use crate::app::Positions;
+use crate::shape::{Balance, Shape, ELEMENTS, MODALITIES};
+
+/// The Jones pattern of the drawn chart and how its planets are balanced
+pub struct ShapeSummary {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub positions: Positions,
+}
+
+impl Component for ShapeSummary {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let shape = Shape::classify(&self.props.positions);
+        let balance = Balance::new(&self.props.positions);
+        let row = |label: &str, counts: Vec<(&str, usize)>| {
+            html! {
+                <tr>
+                    <th>{ label }</th>
+                    { for counts.into_iter().map(|(name, count)| html! {
+                        <td>{ format!("{} {}", name, count) }</td>
+                    }) }
+                </tr>
+            }
+        };
+        html! {
+            <div>
+                <p>
+                    <strong>{ shape.name() }</strong>
+                    { format!(": {}", shape.description()) }
+                </p>
+                <table class="table table-sm">
+                    <tbody>
+                        { row("Elements", ELEMENTS.iter().copied().zip(balance.elements.iter().copied()).collect()) }
+                        { row("Modalities", MODALITIES.iter().copied().zip(balance.modalities.iter().copied()).collect()) }
+                        { row("Hemispheres", vec![
+                            ("Above", balance.above),
+                            ("Below", balance.below),
+                            ("East", balance.east),
+                            ("West", balance.west),
+                        ]) }
+                        { row("Quadrants", vec![
+                            ("I", balance.quadrants[0]),
+                            ("II", balance.quadrants[1]),
+                            ("III", balance.quadrants[2]),
+                            ("IV", balance.quadrants[3]),
+                        ]) }
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+}
@@ -1,23 +1,25 @@
 use log::error;
 use yew::prelude::*;
 
-use crate::app::Positions;
+use crate::app::Chart;
 use crate::input;
 
 pub struct TextInput {
     link: ComponentLink<Self>,
     text: String,
-    on_change: Callback<Positions>,
+    birth: String,
+    on_change: Callback<Chart>,
     error: Option<input::Error>,
 }
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
-    pub on_change: Callback<Positions>,
+    pub on_change: Callback<Chart>,
 }
 
 pub enum Msg {
     TextInput(String),
+    BirthInput(String),
     Clicked,
     FillDefault,
 }
@@ -30,6 +32,7 @@ impl Component for TextInput {
         Self {
             link,
             text: String::new(),
+            birth: String::new(),
             on_change: props.on_change,
             error: None,
         }
@@ -38,17 +41,29 @@ impl Component for TextInput {
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::TextInput(text) => self.text = text,
-            Msg::Clicked => match input::parse_zet9(&self.text) {
-                Ok(positions) => {
-                    self.error = None;
-                    self.on_change.emit(positions);
+            Msg::BirthInput(birth) => self.birth = birth,
+            Msg::Clicked => {
+                match input::parse_zet9(&self.text).and_then(|(positions, speeds)| {
+                    Ok((positions, speeds, input::parse_birth(&self.birth)?))
+                }) {
+                    Ok((positions, speeds, birth)) => {
+                        self.error = None;
+                        self.on_change.emit(Chart {
+                            positions,
+                            speeds,
+                            birth,
+                        });
+                    }
+                    Err(detail) => {
+                        error!("{:?}", detail);
+                        self.error = Some(detail);
+                    }
                 }
-                Err(detail) => {
-                    error!("{:?}", detail);
-                    self.error = Some(detail);
-                }
-            },
-            Msg::FillDefault => self.text = input::SAMPLE.to_string(),
+            }
+            Msg::FillDefault => {
+                self.text = input::SAMPLE.to_string();
+                self.birth = input::SAMPLE_BIRTH.to_string();
+            }
         };
         true
     }
@@ -59,6 +74,7 @@ impl Component for TextInput {
 
     fn view(&self) -> Html {
         let on_text_input = self.link.callback(|e: InputData| Msg::TextInput(e.value));
+        let on_birth_input = self.link.callback(|e: InputData| Msg::BirthInput(e.value));
         let onclick = self.link.callback(|_| Msg::Clicked);
         let fill_default = self.link.callback(|_| Msg::FillDefault);
 
@@ -86,6 +102,17 @@ impl Component for TextInput {
                         oninput=on_text_input
                     />
                 </div>
+                <div class="form-group">
+                    <label for="birth-input">{ "Birth date and time in UT, if known" }</label>
+                    <input
+                        id="birth-input"
+                        class="form-control text-monospace"
+                        type="text"
+                        placeholder="2018-01-12 09:30"
+                        value=self.birth
+                        oninput=on_birth_input
+                    />
+                </div>
                 <div class="btn-group" role="group">
                     <button class="btn btn-primary" onclick=onclick>{ "Submit" }</button>
                     <button class="btn btn-secondary" onclick=fill_default>{ "Insert sample" }</button>
@@ -0,0 +1,149 @@
+use log::error;
+use yew::prelude::*;
+
+use crate::app::try_from_change_data;
+use crate::export;
+use crate::theme::Theme;
+
+/// Choose the colours of the wheel, and move themes in and out as JSON
+pub struct ThemeSelect {
+    link: ComponentLink<Self>,
+    props: Props,
+    /// The JSON of a theme to import
+    json: String,
+    error: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub themes: Vec<Theme>,
+    pub selected: usize,
+    pub on_select: Callback<usize>,
+    pub on_import: Callback<Theme>,
+}
+
+pub enum Msg {
+    Select(usize),
+    JsonInput(String),
+    Import,
+    Export,
+}
+
+/// A file name made from the name of a theme
+fn file_name(theme: &Theme) -> String {
+    let stem: String = theme
+        .name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{}.json", stem)
+}
+
+impl Component for ThemeSelect {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            json: String::new(),
+            error: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Select(i) => {
+                self.props.on_select.emit(i);
+                return false;
+            }
+            Msg::JsonInput(json) => self.json = json,
+            Msg::Import => match Theme::from_json(&self.json) {
+                Ok(theme) => {
+                    self.error = None;
+                    self.json.clear();
+                    self.props.on_import.emit(theme);
+                }
+                Err(err) => self.error = Some(err),
+            },
+            Msg::Export => {
+                let theme = &self.props.themes[self.props.selected];
+                if let Err(err) = export::download(&file_name(theme), theme.to_json().as_bytes()) {
+                    error!("Could not download the theme: {:?}", err);
+                }
+                return false;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let maybe_error = match &self.error {
+            Some(err) => html! { <div class="alert alert-warning">{ err }</div> },
+            None => html! {},
+        };
+        html! {
+            <div>
+                <div class="form-group">
+                    <label for="theme-select">{ "Colours" }</label>
+                    <select
+                        id="theme-select"
+                        class="form-control"
+                        onchange=self.link.callback(|cd| {
+                            Msg::Select(try_from_change_data(cd).unwrap_or(0))
+                        })
+                    >
+                        { for self.props.themes.iter().enumerate().map(|(i, theme)| html! {
+                            <option value=i selected=i == self.props.selected>
+                                { &theme.name }
+                            </option>
+                        }) }
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label for="theme-json">{ "Paste the JSON of a theme to import it" }</label>
+                    { maybe_error }
+                    <textarea
+                        id="theme-json"
+                        class="form-control text-monospace"
+                        rows=3
+                        value=&self.json
+                        oninput=self.link.callback(|e: InputData| Msg::JsonInput(e.value))
+                    />
+                </div>
+                <div class="btn-group" role="group">
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        onclick=self.link.callback(|_| Msg::Import)
+                    >
+                        { "Import theme" }
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        onclick=self.link.callback(|_| Msg::Export)
+                    >
+                        { "Export theme" }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
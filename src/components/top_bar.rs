@@ -1,3 +1,6 @@
+use super::drawing::HarmonicCycle;
+use crate::app::Angles;
+use crate::harmonic;
 use itertools::Itertools;
 use yew::prelude::*;
 
@@ -6,22 +9,25 @@ pub struct TopBar {
     right_text: Vec<String>,
 }
 
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub harmonic_cycle: HarmonicCycle,
+}
+
 impl Component for TopBar {
     type Message = ();
-    type Properties = ();
+    type Properties = Props;
 
-    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
         Self {
-            left_text: "Tropical Zodiac\nEqual Houses\nQuadrants"
-                .lines()
-                .map(String::from)
-                .collect(),
+            left_text: left_text(&props.harmonic_cycle),
             right_text: "DRAW\nzh 2\nZET9".lines().map(String::from).collect(),
         }
     }
 
-    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
-        false
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.left_text = left_text(&props.harmonic_cycle);
+        true
     }
 
     fn update(&mut self, _msg: Self::Message) -> ShouldRender {
@@ -41,3 +47,25 @@ impl Component for TopBar {
         }
     }
 }
+
+fn left_text(harmonic_cycle: &HarmonicCycle) -> Vec<String> {
+    let zodiac = if harmonic_cycle.is_harmonic() {
+        format!(
+            "Harmonic {} Zodiac",
+            harmonic::describe(harmonic_cycle.harmonic)
+        )
+    } else {
+        "Tropical Zodiac".to_string()
+    };
+    let quadrants = if harmonic_cycle.is_turned() {
+        format!("Quadrants from house {}", harmonic_cycle.cycle + 1)
+    } else {
+        "Quadrants".to_string()
+    };
+    let houses = match (harmonic_cycle.is_harmonic(), harmonic_cycle.angles) {
+        (false, _) => "Equal Houses",
+        (true, Angles::Harmonised) => "Equal Houses, Harmonic Angles",
+        (true, Angles::Fixed) => "Equal Houses, Radix Angles",
+    };
+    vec![zodiac, houses.to_string(), quadrants]
+}
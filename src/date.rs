@@ -0,0 +1,222 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+lazy_static! {
+    /// A date with an optional time, like `2018-01-12` or `2018-01-12 09:30`
+    static ref DATE_PAT: regex::Regex =
+        regex::Regex::new(r"^\s*(-?\d+)-(\d{1,2})-(\d{1,2})(?:[ T](\d{1,2}):(\d{2}))?\s*$").unwrap();
+}
+
+const DAYS_PER_YEAR: f64 = 365.25;
+
+const OUT_OF_RANGE: &str = "The date is out of range";
+
+/// A moment in the proleptic Gregorian calendar, in universal time
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+}
+
+impl DateTime {
+    /// Days since 1970-01-01, including the fraction of the day
+    fn days(self) -> f64 {
+        days_from_civil(self.year, self.month, self.day) as f64
+            + f64::from(self.hour * 60 + self.minute) / 1440.
+    }
+
+    /// # Errors
+    ///
+    /// If the year of the result does not fit in an `i32`
+    pub fn add_days(self, days: i32) -> Result<Self, String> {
+        let (year, month, day) =
+            civil_from_days(days_from_civil(self.year, self.month, self.day) + i64::from(days));
+        Ok(Self {
+            year: i32::try_from(year).map_err(|_| OUT_OF_RANGE.to_string())?,
+            month,
+            day,
+            ..self
+        })
+    }
+
+    /// Add whole months, keeping the day of the month where possible and moving it to the end
+    /// of the month otherwise
+    ///
+    /// # Errors
+    ///
+    /// If the year of the result does not fit in an `i32`
+    pub fn add_months(self, months: i32) -> Result<Self, String> {
+        self.shift_months(i64::from(months))
+    }
+
+    /// # Errors
+    ///
+    /// If the year of the result does not fit in an `i32`
+    pub fn add_years(self, years: i32) -> Result<Self, String> {
+        self.shift_months(12 * i64::from(years))
+    }
+
+    fn shift_months(self, months: i64) -> Result<Self, String> {
+        let index = i64::from(self.year) * 12 + i64::from(self.month) - 1 + months;
+        let year = i32::try_from(index.div_euclid(12)).map_err(|_| OUT_OF_RANGE.to_string())?;
+        let month = index.rem_euclid(12) as u32 + 1;
+        Ok(Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+            ..self
+        })
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let caps = DATE_PAT
+            .captures(s)
+            .ok_or_else(|| format!("Expected a date like 2018-01-12 09:30, got {:?}", s))?;
+        // The groups are digit sequences and can only fail to parse by overflowing
+        let field = |i: usize| -> Result<i64, String> {
+            caps.get(i)
+                .map_or(Ok(0), |m| m.as_str().parse())
+                .map_err(|_| format!("Number out of range in {:?}", s))
+        };
+        let year = i32::try_from(field(1)?).map_err(|_| format!("Year out of range in {:?}", s))?;
+        let (month, day, hour, minute) = (field(2)? as u32, field(3)? as u32, field(4)?, field(5)?);
+        if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+            return Err(format!("No such date: {:?}", s));
+        }
+        if hour > 23 || minute > 59 {
+            return Err(format!("No such time: {:?}", s));
+        }
+        Ok(Self {
+            year,
+            month,
+            day,
+            hour: hour as u32,
+            minute: minute as u32,
+        })
+    }
+}
+
+/// The age in years at `at` of someone born at `birth`
+pub fn age(birth: DateTime, at: DateTime) -> f64 {
+    (at.days() - birth.days()) / DAYS_PER_YEAR
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01, after Howard Hinnant's `days_from_civil`
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The inverse of `days_from_civil`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> DateTime {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_dates_and_times() {
+        assert_eq!(
+            date("2018-01-12 09:30"),
+            DateTime {
+                year: 2018,
+                month: 1,
+                day: 12,
+                hour: 9,
+                minute: 30,
+            }
+        );
+        assert_eq!(date(" -44-3-15 ").year, -44);
+        for bad in &["2018-02-29", "2018-13-01", "2018-01-12 24:00", "12/01/2018"] {
+            assert!(bad.parse::<DateTime>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn refuses_years_that_do_not_fit() {
+        assert!("4294969314-01-12".parse::<DateTime>().is_err());
+        assert!("99999999999999999999-01-12".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn steps_keep_the_day_within_the_month() {
+        let start = date("2020-01-31 12:00");
+        assert_eq!(start.add_months(1).unwrap().to_string(), "2020-02-29 12:00");
+        assert_eq!(
+            start.add_months(-2).unwrap().to_string(),
+            "2019-11-30 12:00"
+        );
+        assert_eq!(
+            date("2020-02-29").add_years(1).unwrap().to_string(),
+            "2021-02-28 00:00"
+        );
+        assert_eq!(start.add_days(366).unwrap().to_string(), "2021-01-31 12:00");
+    }
+
+    #[test]
+    fn steps_past_the_supported_years_fail() {
+        let start = date("2147483647-12-01");
+        assert!(start.add_months(1).is_err());
+        assert!(start.add_days(31).is_err());
+        assert!(date("2000-01-01").add_years(i32::MAX).is_err());
+    }
+
+    #[test]
+    fn age_counts_years_from_birth() {
+        let age = age(date("2000-01-01"), date("2030-01-01"));
+        assert!((age - 30.).abs() < 0.01, "{}", age);
+    }
+}
@@ -0,0 +1,284 @@
+//! Essential dignities of the seven traditional planets. Signs are indexed from Aries as in
+//! `ZET9_ZODIAC` and planets as in `Positions`.
+
+use crate::app::Positions;
+use crate::lots;
+
+const SUN: usize = 0;
+const MOON: usize = 1;
+const MERCURY: usize = 2;
+const VENUS: usize = 3;
+const MARS: usize = 4;
+const JUPITER: usize = 5;
+const SATURN: usize = 6;
+
+/// The number of traditional planets, from the Sun to Saturn
+pub const PLANETS: usize = 7;
+
+/// The domicile ruler of each sign
+pub const DOMICILE: [usize; 12] = [
+    MARS, VENUS, MERCURY, MOON, SUN, MERCURY, VENUS, MARS, JUPITER, SATURN, SATURN, JUPITER,
+];
+
+/// The planet exalted in each sign, if any
+pub const EXALTATION: [Option<usize>; 12] = [
+    Some(SUN),
+    Some(MOON),
+    None,
+    Some(JUPITER),
+    None,
+    Some(MERCURY),
+    Some(SATURN),
+    None,
+    None,
+    Some(MARS),
+    None,
+    Some(VENUS),
+];
+
+/// The Dorothean triplicity rulers of fire, earth, air and water: by day, by night and
+/// participating
+const TRIPLICITY: [[usize; 3]; 4] = [
+    [SUN, JUPITER, SATURN],
+    [VENUS, MOON, MARS],
+    [SATURN, MERCURY, JUPITER],
+    [VENUS, MARS, MOON],
+];
+
+/// The Egyptian terms of each sign, as the ruler and the degree the term ends at
+const TERMS: [[(usize, f32); 5]; 12] = [
+    [
+        (JUPITER, 6.),
+        (VENUS, 12.),
+        (MERCURY, 20.),
+        (MARS, 25.),
+        (SATURN, 30.),
+    ],
+    [
+        (VENUS, 8.),
+        (MERCURY, 14.),
+        (JUPITER, 22.),
+        (SATURN, 27.),
+        (MARS, 30.),
+    ],
+    [
+        (MERCURY, 6.),
+        (JUPITER, 12.),
+        (VENUS, 17.),
+        (MARS, 24.),
+        (SATURN, 30.),
+    ],
+    [
+        (MARS, 7.),
+        (VENUS, 13.),
+        (MERCURY, 19.),
+        (JUPITER, 26.),
+        (SATURN, 30.),
+    ],
+    [
+        (JUPITER, 6.),
+        (VENUS, 11.),
+        (SATURN, 18.),
+        (MERCURY, 24.),
+        (MARS, 30.),
+    ],
+    [
+        (MERCURY, 7.),
+        (VENUS, 17.),
+        (JUPITER, 21.),
+        (MARS, 28.),
+        (SATURN, 30.),
+    ],
+    [
+        (SATURN, 6.),
+        (MERCURY, 14.),
+        (JUPITER, 21.),
+        (VENUS, 28.),
+        (MARS, 30.),
+    ],
+    [
+        (MARS, 7.),
+        (VENUS, 11.),
+        (MERCURY, 19.),
+        (JUPITER, 24.),
+        (SATURN, 30.),
+    ],
+    [
+        (JUPITER, 12.),
+        (VENUS, 17.),
+        (MERCURY, 21.),
+        (SATURN, 26.),
+        (MARS, 30.),
+    ],
+    [
+        (MERCURY, 7.),
+        (JUPITER, 14.),
+        (VENUS, 22.),
+        (SATURN, 26.),
+        (MARS, 30.),
+    ],
+    [
+        (MERCURY, 7.),
+        (VENUS, 13.),
+        (JUPITER, 20.),
+        (MARS, 25.),
+        (SATURN, 30.),
+    ],
+    [
+        (VENUS, 12.),
+        (JUPITER, 16.),
+        (MERCURY, 19.),
+        (MARS, 28.),
+        (SATURN, 30.),
+    ],
+];
+
+/// The Chaldean order of the planets, which rules the faces in turn from the first of Aries
+const CHALDEAN: [usize; 7] = [MARS, SUN, VENUS, MERCURY, MOON, SATURN, JUPITER];
+
+/// The sign a position falls in
+pub fn sign(position: f32) -> usize {
+    (position.rem_euclid(360.) / 30.) as usize % 12
+}
+
+/// The rulers of each dignity at a position in the zodiac
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rulers {
+    pub domicile: usize,
+    pub exaltation: Option<usize>,
+    /// The triplicity ruler of the chart's sect
+    pub triplicity: usize,
+    pub term: usize,
+    pub face: usize,
+    /// The planet in detriment here, being the ruler of the opposite sign
+    pub detriment: usize,
+    /// The planet in fall here, being the one exalted in the opposite sign
+    pub fall: Option<usize>,
+}
+
+impl Rulers {
+    pub fn at(position: f32, day: bool) -> Self {
+        let position = position.rem_euclid(360.);
+        let sign = sign(position);
+        let degree = position - 30. * sign as f32;
+        let sect = usize::from(!day);
+        let triplicity = TRIPLICITY[sign % 4][sect];
+        let term = TERMS[sign]
+            .iter()
+            .find(|&&(_, end)| degree < end)
+            .map_or(TERMS[sign][4].0, |&(ruler, _)| ruler);
+        Self {
+            domicile: DOMICILE[sign],
+            exaltation: EXALTATION[sign],
+            triplicity,
+            term,
+            face: CHALDEAN[(position / 10.) as usize % 7],
+            detriment: DOMICILE[(sign + 6) % 12],
+            fall: EXALTATION[(sign + 6) % 12],
+        }
+    }
+}
+
+/// The essential dignities and debilities of one planet
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dignity {
+    pub planet: usize,
+    pub rulers: Rulers,
+    pub domicile: bool,
+    pub exaltation: bool,
+    pub triplicity: bool,
+    pub term: bool,
+    pub face: bool,
+    pub detriment: bool,
+    pub fall: bool,
+}
+
+impl Dignity {
+    /// Lilly's points: 5 for domicile, 4 exaltation, 3 triplicity, 2 term and 1 face, less 5
+    /// for detriment, 4 for fall and 5 for being peregrine
+    pub fn score(&self) -> i32 {
+        let points = [
+            (self.domicile, 5),
+            (self.exaltation, 4),
+            (self.triplicity, 3),
+            (self.term, 2),
+            (self.face, 1),
+            (self.detriment, -5),
+            (self.fall, -4),
+            (self.is_peregrine(), -5),
+        ];
+        points
+            .iter()
+            .filter(|&&(has, _)| has)
+            .map(|&(_, score)| score)
+            .sum()
+    }
+
+    /// Without any essential dignity
+    pub fn is_peregrine(&self) -> bool {
+        !(self.domicile || self.exaltation || self.triplicity || self.term || self.face)
+    }
+}
+
+/// The dignities of the seven traditional planets
+pub fn dignities(positions: &Positions) -> Vec<Dignity> {
+    let day = lots::is_day(positions);
+    positions.0[..PLANETS]
+        .iter()
+        .enumerate()
+        .map(|(planet, &position)| {
+            let rulers = Rulers::at(position, day);
+            Dignity {
+                planet,
+                domicile: rulers.domicile == planet,
+                exaltation: rulers.exaltation == Some(planet),
+                triplicity: rulers.triplicity == planet,
+                term: rulers.term == planet,
+                face: rulers.face == planet,
+                detriment: rulers.detriment == planet,
+                fall: rulers.fall == Some(planet),
+                rulers,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A day chart with the Sun in Leo and Mars in Cancer
+    fn chart() -> Positions {
+        let mut positions = [0.; 13];
+        positions[SUN] = 130.;
+        positions[MARS] = 100.;
+        positions[12] = 300.;
+        Positions(positions)
+    }
+
+    #[test]
+    fn rulers_follow_sign_degree_and_sect() {
+        let rulers = Rulers::at(130., true);
+        assert_eq!(rulers.domicile, SUN);
+        assert_eq!(rulers.exaltation, None);
+        assert_eq!(rulers.triplicity, SUN);
+        assert_eq!(rulers.term, VENUS);
+        assert_eq!(rulers.face, JUPITER);
+        assert_eq!(rulers.detriment, SATURN);
+        assert_eq!(Rulers::at(100., false).triplicity, MARS);
+        assert_eq!(Rulers::at(-1., true).domicile, JUPITER);
+    }
+
+    #[test]
+    fn scores_add_up_dignities_and_debilities() {
+        let dignities = dignities(&chart());
+        assert_eq!(dignities.len(), PLANETS);
+        let sun = &dignities[SUN];
+        assert!(sun.domicile && sun.triplicity && !sun.term);
+        assert_eq!(sun.score(), 8);
+        let mars = &dignities[MARS];
+        assert!(mars.fall && mars.is_peregrine());
+        assert_eq!(mars.score(), -9);
+    }
+}
@@ -0,0 +1,201 @@
+//! Dispositor chains and mutual receptions of the ten planets from the Sun to Pluto
+
+use crate::app::Positions;
+use crate::dignities::{self, DOMICILE, EXALTATION};
+
+const URANUS: usize = 7;
+const NEPTUNE: usize = 8;
+const PLUTO: usize = 9;
+
+/// The number of planets that take part, from the Sun to Pluto
+pub const PLANETS: usize = 10;
+
+/// Whether the outer planets rule Scorpio, Aquarius and Pisces
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rulership {
+    Traditional,
+    Modern,
+}
+
+impl Rulership {
+    pub fn name(self) -> &'static str {
+        match self {
+            Rulership::Traditional => "Traditional",
+            Rulership::Modern => "Modern",
+        }
+    }
+
+    /// The domicile ruler of a sign
+    pub fn ruler(self, sign: usize) -> usize {
+        match (self, sign) {
+            (Rulership::Modern, 7) => PLUTO,
+            (Rulership::Modern, 10) => URANUS,
+            (Rulership::Modern, 11) => NEPTUNE,
+            _ => DOMICILE[sign],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Reception {
+    Domicile,
+    Exaltation,
+}
+
+impl Reception {
+    pub fn name(self) -> &'static str {
+        match self {
+            Reception::Domicile => "domicile",
+            Reception::Exaltation => "exaltation",
+        }
+    }
+}
+
+/// Two planets each in a sign where the other is dignified
+#[derive(Clone, Debug, PartialEq)]
+pub struct MutualReception {
+    pub first: usize,
+    pub second: usize,
+    pub reception: Reception,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dispositors {
+    /// The dispositor of each planet, being the ruler of the sign it is in
+    pub rulers: Vec<usize>,
+    /// Planets in their own sign, where chains come to an end
+    pub finals: Vec<usize>,
+    /// Chains that close on themselves without reaching a planet in its own sign
+    pub loops: Vec<Vec<usize>>,
+    pub receptions: Vec<MutualReception>,
+}
+
+impl Dispositors {
+    pub fn new(positions: &Positions, rulership: Rulership) -> Self {
+        let signs: Vec<usize> = positions.0[..PLANETS]
+            .iter()
+            .map(|&p| dignities::sign(p))
+            .collect();
+        let rulers: Vec<usize> = signs.iter().map(|&s| rulership.ruler(s)).collect();
+        let finals = (0..PLANETS).filter(|&p| rulers[p] == p).collect();
+
+        // Every chain ends in a cycle, which is a loop unless it has a single planet
+        let mut loops: Vec<Vec<usize>> = Vec::new();
+        for start in 0..PLANETS {
+            let mut chain = vec![start];
+            let mut next = rulers[start];
+            while !chain.contains(&next) {
+                chain.push(next);
+                next = rulers[next];
+            }
+            let cycle_start = chain.iter().position(|&p| p == next).unwrap();
+            let mut cycle = chain.split_off(cycle_start);
+            if cycle.len() < 2 {
+                continue;
+            }
+            // Start each loop at its lowest planet so that it is only found once
+            let lowest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+            cycle.rotate_left(lowest);
+            if !loops.contains(&cycle) {
+                loops.push(cycle);
+            }
+        }
+
+        let mut receptions = Vec::new();
+        for first in 0..PLANETS {
+            for second in first + 1..PLANETS {
+                if rulers[first] == second && rulers[second] == first {
+                    receptions.push(MutualReception {
+                        first,
+                        second,
+                        reception: Reception::Domicile,
+                    });
+                }
+                if EXALTATION[signs[first]] == Some(second)
+                    && EXALTATION[signs[second]] == Some(first)
+                {
+                    receptions.push(MutualReception {
+                        first,
+                        second,
+                        reception: Reception::Exaltation,
+                    });
+                }
+            }
+        }
+
+        Self {
+            rulers,
+            finals,
+            loops,
+            receptions,
+        }
+    }
+
+    /// The planet every chain ends at, if there is only one
+    pub fn final_dispositor(&self) -> Option<usize> {
+        if self.finals.len() == 1 && self.loops.is_empty() {
+            Some(self.finals[0])
+        } else {
+            None
+        }
+    }
+
+    pub fn in_reception(&self, a: usize, b: usize, reception: Reception) -> bool {
+        self.receptions.iter().any(|r| {
+            r.reception == reception
+                && ((r.first == a && r.second == b) || (r.first == b && r.second == a))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUN: usize = 0;
+    const MERCURY: usize = 2;
+    const VENUS: usize = 3;
+    const MARS: usize = 4;
+
+    /// A chart with the planets in Aries but for those at `others`
+    fn chart(others: &[(usize, f32)]) -> Positions {
+        let mut positions = [10.; 13];
+        for &(planet, position) in others {
+            positions[planet] = position;
+        }
+        Positions(positions)
+    }
+
+    #[test]
+    fn chains_end_at_a_single_final_dispositor() {
+        let dispositors = Dispositors::new(&chart(&[]), Rulership::Traditional);
+        assert_eq!(dispositors.rulers, vec![MARS; PLANETS]);
+        assert_eq!(dispositors.final_dispositor(), Some(MARS));
+        assert!(dispositors.receptions.is_empty());
+    }
+
+    #[test]
+    fn finds_loops_and_mutual_receptions() {
+        let positions = chart(&[(SUN, 280.), (MERCURY, 40.), (VENUS, 70.)]);
+        let dispositors = Dispositors::new(&positions, Rulership::Traditional);
+        assert_eq!(dispositors.finals, vec![MARS]);
+        assert_eq!(dispositors.loops, vec![vec![MERCURY, VENUS]]);
+        assert_eq!(dispositors.final_dispositor(), None);
+        assert!(dispositors.in_reception(VENUS, MERCURY, Reception::Domicile));
+        assert!(dispositors.in_reception(SUN, MARS, Reception::Exaltation));
+        assert!(!dispositors.in_reception(SUN, MARS, Reception::Domicile));
+    }
+
+    #[test]
+    fn modern_rulers_take_the_outer_signs() {
+        let positions = chart(&[(SUN, 220.)]);
+        assert_eq!(
+            Dispositors::new(&positions, Rulership::Traditional).rulers[SUN],
+            MARS
+        );
+        assert_eq!(
+            Dispositors::new(&positions, Rulership::Modern).rulers[SUN],
+            PLUTO
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Saving the chart as a file
+
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, HtmlElement, Url};
+
+use crate::glyphs::GlyphStyle;
+use crate::sheet::Paper;
+use crate::svg::Element;
+#[cfg(feature = "png")]
+use crate::theme::Theme;
+use crate::wheel::Wheel;
+
+/// The namespace of the chart description in `<metadata>`
+const METADATA_NAMESPACE: &str = "urn:chartdraw";
+
+/// What is known about a chart and how it was drawn, to be stored along with the picture
+pub struct Metadata<'a> {
+    pub title: String,
+    /// The settings of the drawing, by name
+    pub settings: Vec<(&'static str, String)>,
+    /// The radix positions, by name
+    pub points: Vec<(&'a str, f32)>,
+}
+
+impl Metadata<'_> {
+    fn to_element(&self) -> Element {
+        let settings = self.settings.iter().fold(
+            Element::new("chartdraw:settings"),
+            |element, (name, value)| element.attr(name, value),
+        );
+        let points = self.points.iter().map(|(name, longitude)| {
+            Element::new("chartdraw:point")
+                .attr("name", name)
+                .attr("longitude", longitude)
+        });
+        Element::new("metadata").child(
+            Element::new("chartdraw:chart")
+                .attr("xmlns:chartdraw", METADATA_NAMESPACE)
+                .child(settings)
+                .children(points),
+        )
+    }
+}
+
+/// The wheel as a standalone SVG file of `size` pixels square. The glyphs and labels are
+/// drawn as outlines so that the file looks the same without the fonts, and the file has
+/// none of the tooltips of the page.
+#[must_use]
+pub fn svg_document(wheel: &Wheel, metadata: &Metadata, size: u32) -> String {
+    let wheel = Wheel {
+        glyph_style: GlyphStyle::Outline,
+        interactive: false,
+        ..*wheel
+    };
+    let mut svg = wheel.render().attr("width", size).attr("height", size);
+    svg.children.insert(0, metadata.to_element().into());
+    svg.children
+        .insert(0, Element::new("title").text(&metadata.title).into());
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}\n",
+        svg.to_xml()
+    )
+}
+
+/// The kind of file the chart is saved as
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Svg {
+        size: u32,
+    },
+    /// A raster image, which needs the `png` feature
+    Png {
+        size: u32,
+        dpi: f32,
+        background: Background,
+    },
+    /// A printable sheet with the wheel and tables
+    Pdf {
+        paper: Paper,
+    },
+}
+
+/// What is behind the chart in a raster image
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Background {
+    Transparent,
+    /// A solid colour, as red, green and blue
+    Solid(u8, u8, u8),
+}
+
+/// The size of the largest PNG image, whose pixels take 64 MB. A browser tab can run out of
+/// memory on much larger ones.
+pub const MAX_PNG_SIZE: u32 = 4000;
+
+/// The wheel as a PNG image of `size` pixels square, which viewers should print at `dpi` dots
+/// per inch
+///
+/// # Errors
+///
+/// Fails if the document does not parse or the image is larger than `MAX_PNG_SIZE`
+#[cfg(feature = "png")]
+pub fn png(
+    wheel: &Wheel,
+    metadata: &Metadata,
+    size: u32,
+    dpi: f32,
+    background: Background,
+) -> Result<Vec<u8>, String> {
+    let pixmap = rasterise(wheel, metadata, size, background)?;
+    let png = pixmap.encode_png().map_err(|error| error.to_string())?;
+    Ok(with_resolution(png, dpi))
+}
+
+/// The pixels of the wheel on `background`, which takes the place of the theme's own
+#[cfg(feature = "png")]
+fn rasterise(
+    wheel: &Wheel,
+    metadata: &Metadata,
+    size: u32,
+    background: Background,
+) -> Result<resvg::tiny_skia::Pixmap, String> {
+    use resvg::tiny_skia::{Color, Pixmap, Transform};
+    use resvg::usvg::{Options, Tree};
+
+    if size > MAX_PNG_SIZE {
+        return Err(format!(
+            "PNG images can be at most {} pixels square",
+            MAX_PNG_SIZE
+        ));
+    }
+    let theme = Theme {
+        background: "none".to_string(),
+        ..wheel.theme.clone()
+    };
+    let svg = svg_document(
+        &Wheel {
+            theme: &theme,
+            ..*wheel
+        },
+        metadata,
+        size,
+    );
+    // The glyphs and labels of an exported wheel are all outlines, so the rasteriser needs no
+    // fonts, which a browser would not let it at anyway
+    let tree = Tree::from_str(&svg, &Options::default()).map_err(|error| error.to_string())?;
+    let mut pixmap =
+        Pixmap::new(size, size).ok_or_else(|| format!("Can't make an image of size {}", size))?;
+    if let Background::Solid(red, green, blue) = background {
+        pixmap.fill(Color::from_rgba8(red, green, blue, 255));
+    }
+    let scale = size as f32 / tree.size().width();
+    resvg::render(
+        &tree,
+        Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+    Ok(pixmap)
+}
+
+/// Record the resolution of a PNG image by adding a `pHYs` chunk right after the header
+#[cfg(feature = "png")]
+fn with_resolution(mut png: Vec<u8>, dpi: f32) -> Vec<u8> {
+    // The signature takes 8 bytes and the header chunk 25
+    const AFTER_HEADER: usize = 33;
+    let pixels_per_metre = (dpi / 0.0254).round() as u32;
+    let mut chunk = b"pHYs".to_vec();
+    chunk.extend_from_slice(&pixels_per_metre.to_be_bytes());
+    chunk.extend_from_slice(&pixels_per_metre.to_be_bytes());
+    // The unit is the metre
+    chunk.push(1);
+    let crc = crc32(&chunk);
+    let mut bytes = 9_u32.to_be_bytes().to_vec();
+    bytes.extend(chunk);
+    bytes.extend_from_slice(&crc.to_be_bytes());
+    png.splice(AFTER_HEADER..AFTER_HEADER, bytes);
+    png
+}
+
+/// The checksum of a PNG chunk, computed bit by bit since there are only a few bytes
+#[cfg(feature = "png")]
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+/// How long the browser gets to fetch a download before its URL is revoked, in milliseconds
+const REVOKE_DELAY: i32 = 10_000;
+
+/// Have the browser save `contents` as a file called `filename`
+///
+/// # Errors
+///
+/// Fails if the browser cannot create the file or the link to it
+pub fn download(filename: &str, contents: &[u8]) -> Result<(), JsValue> {
+    let parts = Array::new();
+    parts.push(&Uint8Array::from(contents));
+    let blob = Blob::new_with_u8_array_sequence(&parts)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+    let window =
+        web_sys::window().ok_or_else(|| JsValue::from_str("There is no window to download in"))?;
+    let document = window
+        .document()
+        .ok_or_else(|| JsValue::from_str("There is no document to download from"))?;
+    let anchor = document.create_element("a")?;
+    anchor.set_attribute("href", &url)?;
+    anchor.set_attribute("download", filename)?;
+    anchor.dyn_into::<HtmlElement>()?.click();
+    // Some browsers cancel the download if the URL goes away before they have fetched it
+    let revoke = Closure::once_into_js(move || Url::revoke_object_url(&url));
+    window.set_timeout_with_callback_and_timeout_and_arguments_0(
+        revoke.unchecked_ref(),
+        REVOKE_DELAY,
+    )?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "png"))]
+mod tests {
+    use super::*;
+    use crate::input;
+    use crate::layout::Layout;
+
+    #[test]
+    fn transparent_images_leave_out_the_theme_background() {
+        let (positions, speeds) = input::parse_zet9(input::SAMPLE).unwrap();
+        let theme = Theme::dark();
+        let wheel = Wheel {
+            positions: &positions,
+            cycle: 0,
+            aspect: true,
+            aspect_types: Default::default(),
+            orb: 8.,
+            planets: 9,
+            layout: Layout::default(),
+            antiscia: false,
+            lots: &[],
+            speeds: &speeds,
+            glyph_style: GlyphStyle::Outline,
+            theme: &theme,
+            focus: None,
+            interactive: false,
+        };
+        let metadata = Metadata {
+            title: "Sample".to_string(),
+            settings: Vec::new(),
+            points: Vec::new(),
+        };
+        let corner = |background| {
+            rasterise(&wheel, &metadata, 200, background)
+                .unwrap()
+                .pixel(0, 0)
+                .unwrap()
+        };
+        assert_eq!(corner(Background::Transparent).alpha(), 0);
+        let white = corner(Background::Solid(255, 255, 255));
+        assert_eq!((white.red(), white.green(), white.blue()), (255, 255, 255));
+        assert!(png(
+            &wheel,
+            &metadata,
+            MAX_PNG_SIZE + 1,
+            96.,
+            Background::Transparent
+        )
+        .is_err());
+    }
+}
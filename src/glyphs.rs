@@ -0,0 +1,281 @@
+//! Outlines of the astrological glyphs, so that a drawing does not depend on the fonts at hand.
+//! Each glyph is a stroked path drawn in a box from 0 to 10 across, standing on the baseline
+//! with its top at -10, like a letter in a font of size 10.
+
+use crate::svg::{Element, Node};
+
+/// Outlines of the zodiac signs, from Aries
+pub const ZODIAC: [&str; 12] = [
+    // Aries
+    "M 5 0 V -6 C 5 -10 1 -10 1 -7 M 5 -6 C 5 -10 9 -10 9 -7",
+    // Taurus
+    "M 8.5 -3.5 A 3.5 3.5 0 1 1 1.5 -3.5 A 3.5 3.5 0 1 1 8.5 -3.5 M 1 -10 C 2 -6.5 8 -6.5 9 -10",
+    // Gemini
+    "M 1 -10 Q 5 -8.5 9 -10 M 1 0 Q 5 -1.5 9 0 M 3.5 -9.2 V -0.8 M 6.5 -9.2 V -0.8",
+    // Cancer
+    "M 9 -7 C 7 -10 2 -10 1 -7 M 1 -3 C 3 0 8 0 9 -3 \
+     M 4 -6.5 A 1.5 1.5 0 1 1 1 -6.5 A 1.5 1.5 0 1 1 4 -6.5 \
+     M 9 -3.5 A 1.5 1.5 0 1 1 6 -3.5 A 1.5 1.5 0 1 1 9 -3.5",
+    // Leo
+    "M 5 -3 A 2 2 0 1 1 1 -3 A 2 2 0 1 1 5 -3 C 5 -6 3 -8 4 -9.5 C 5 -11 9 -10 8 -6 \
+     C 7 -3 7 -1 9 0",
+    // Virgo
+    "M 1 -8 C 2 -9 2.5 -8 2.5 -7 V 0 M 2.5 -7 C 2.5 -10 5 -10 5 -7 V 0 \
+     M 5 -7 C 5 -10 7.5 -10 7.5 -7 V -2 C 7.5 0 9 -1 9.5 -3 M 7.5 -3 C 8 -1 8 0 6.5 0.5",
+    // Libra
+    "M 1 0 H 9 M 1 -3 H 3.2 A 2.5 2.5 0 1 1 6.8 -3 H 9",
+    // Scorpio
+    "M 1 -8 C 2 -9 2.5 -8 2.5 -7 V 0 M 2.5 -7 C 2.5 -10 5 -10 5 -7 V 0 \
+     M 5 -7 C 5 -10 7.5 -10 7.5 -7 V -1 Q 7.5 0 9 0 M 8 -1 L 9 0 L 8 1",
+    // Sagittarius
+    "M 1 0 L 9 -9 M 5 -9 H 9 V -5 M 2.5 -5.5 L 5.5 -2.5",
+    // Capricorn
+    "M 1 -8 C 2 -9 2.5 -8 2.5 -7 V -1 M 2.5 -7 C 3 -10 6 -10 6 -6 V -2 \
+     A 1.75 1.75 0 1 0 9.5 -2 A 1.75 1.75 0 1 0 6 -2 Q 5.5 0 4 0.5",
+    // Aquarius
+    "M 1 -5 L 3 -7 L 5 -5 L 7 -7 L 9 -5 M 1 -1 L 3 -3 L 5 -1 L 7 -3 L 9 -1",
+    // Pisces
+    "M 2 -10 Q 5 -5 2 0 M 8 -10 Q 5 -5 8 0 M 1 -5 H 9",
+];
+
+/// Outlines of the bodies, in the order they are stored in `Positions`
+pub const BODIES: [&str; 11] = [
+    // Sun
+    "M 9.5 -5 A 4.5 4.5 0 1 1 0.5 -5 A 4.5 4.5 0 1 1 9.5 -5 \
+     M 5.6 -5 A 0.6 0.6 0 1 1 4.4 -5 A 0.6 0.6 0 1 1 5.6 -5",
+    // Moon
+    "M 4 -10 A 5 5 0 0 1 4 0 A 2.5 5 0 0 0 4 -10 Z",
+    // Mercury
+    "M 7.5 -5.5 A 2.5 2.5 0 1 1 2.5 -5.5 A 2.5 2.5 0 1 1 7.5 -5.5 M 2.5 -10 A 2.5 2 0 0 0 7.5 -10 \
+     M 5 -3 V 0.5 M 3.3 -1 H 6.7",
+    // Venus
+    "M 8.5 -6.5 A 3.5 3.5 0 1 1 1.5 -6.5 A 3.5 3.5 0 1 1 8.5 -6.5 M 5 -3 V 1 M 3 -1 H 7",
+    // Mars
+    "M 7.5 -4 A 3.5 3.5 0 1 1 0.5 -4 A 3.5 3.5 0 1 1 7.5 -4 M 6.5 -6.5 L 9.5 -9.5 \
+     M 6 -9.5 H 9.5 V -6",
+    // Jupiter
+    "M 1.5 -8 C 2 -11 6 -10 5 -7 C 4.5 -5 2 -3 1 -3 H 9 M 7 -10 V 0",
+    // Saturn
+    "M 1.5 -9 H 5.5 M 3 -10.5 V -1 M 3 -5 C 4 -7 8 -7 7.5 -4.5 C 7 -2.5 5.5 -1.5 6.5 0 \
+     Q 7.5 0.5 8.5 -0.5",
+    // Uranus
+    "M 2 -10 V -4 M 8 -10 V -4 M 2 -7 H 8 M 5 -10 V -2.5 \
+     M 6.2 -1.3 A 1.2 1.2 0 1 1 3.8 -1.3 A 1.2 1.2 0 1 1 6.2 -1.3",
+    // Neptune
+    "M 1 -9 C 1 -4 9 -4 9 -9 M 5 -10 V 0.5 M 3 -1.5 H 7",
+    // Pluto
+    "M 2 -10 V 0 H 8 M 2 -10 H 5 A 2.5 2.5 0 0 1 5 -5 H 2",
+    // Node
+    "M 3 -1.5 C 0 -5 1 -10 5 -10 C 9 -10 10 -5 7 -1.5 \
+     M 3.7 -1 A 1.2 1.2 0 1 1 1.3 -1 A 1.2 1.2 0 1 1 3.7 -1 \
+     M 8.7 -1 A 1.2 1.2 0 1 1 6.3 -1 A 1.2 1.2 0 1 1 8.7 -1",
+];
+
+/// Outlines of the aspects, in the order of `aspect::Type::ALL`
+pub const ASPECTS: [&str; 6] = [
+    // Conjunction
+    "M 6 -4 A 3 3 0 1 1 0 -4 A 3 3 0 1 1 6 -4 M 5.1 -6.1 L 9 -10",
+    // Semi-sextile
+    "M 1 -10 L 5 -3 L 9 -10 M 1 0 H 9",
+    // Sextile
+    "M 5 -9.5 V -0.5 M 1.1 -7.25 L 8.9 -2.75 M 1.1 -2.75 L 8.9 -7.25",
+    // Square
+    "M 1 -9 H 9 V -1 H 1 Z",
+    // Trine
+    "M 5 -9.5 L 9.5 -1 H 0.5 Z",
+    // Opposition
+    "M 3.8 -2 A 1.8 1.8 0 1 1 0.2 -2 A 1.8 1.8 0 1 1 3.8 -2 \
+     M 9.8 -8 A 1.8 1.8 0 1 1 6.2 -8 A 1.8 1.8 0 1 1 9.8 -8 M 3.3 -3.3 L 6.7 -6.7",
+];
+
+/// The characters for the aspects, in the order of `aspect::Type::ALL`
+pub const ASPECT_CHARACTERS: [char; 6] = ['☌', '⚺', '⚹', '□', '△', '☍'];
+
+/// Outline of the mark for a body moving backwards
+pub const RETROGRADE: &str =
+    "M 2 0 V -10 H 5.5 A 2.75 2.75 0 0 1 5.5 -4.5 H 2 M 5 -4.5 L 9.5 0.5 M 6 -0.5 L 9.5 -3.5";
+
+pub const RETROGRADE_CHARACTER: char = '℞';
+
+/// The space a letter takes in `letter` outlines
+pub const ADVANCE: f32 = 7.;
+
+/// The scale that makes a letter outline, with capitals 10 high, as large as the letters of
+/// text at a font size of 1
+pub const LETTER_SCALE: f32 = 0.07;
+
+/// Outline of a letter, digit or the few other characters that lot symbols and position
+/// labels are written with, along with the space it takes. Letters are drawn in a box 5
+/// across, standing on the baseline with capitals reaching to -10 and descenders to 3.
+#[must_use]
+pub fn letter(character: char) -> Option<(&'static str, f32)> {
+    let outline = match character {
+        'A' => "M 0.5 0 L 3 -10 L 5.5 0 M 1.25 -3 H 4.75",
+        'B' => {
+            "M 0.5 0 V -10 H 2.75 A 2.5 2.5 0 0 1 2.75 -5 H 0.5 M 2.75 -5 H 3 \
+                A 2.5 2.5 0 0 1 3 0 H 0.5"
+        }
+        'C' => "M 5.5 -8.5 C 4.5 -10.5 0.5 -10.5 0.5 -5 C 0.5 0.5 4.5 0.5 5.5 -1.5",
+        'D' => "M 0.5 0 V -10 H 2 C 6.5 -10 6.5 0 2 0 Z",
+        'E' => "M 5.5 -10 H 0.5 V 0 H 5.5 M 0.5 -5 H 4.5",
+        'F' => "M 5.5 -10 H 0.5 V 0 M 0.5 -5 H 4.5",
+        'G' => "M 5.5 -8.5 C 4.5 -10.5 0.5 -10.5 0.5 -5 C 0.5 0.5 5.5 0.5 5.5 -4 H 3.5",
+        'H' => "M 0.5 -10 V 0 M 5.5 -10 V 0 M 0.5 -5 H 5.5",
+        'I' => "M 1.5 -10 H 4.5 M 3 -10 V 0 M 1.5 0 H 4.5",
+        'J' => "M 5 -10 V -3 C 5 0.5 1 0.5 0.5 -2",
+        'K' => "M 0.5 -10 V 0 M 5.5 -10 L 0.5 -4 M 2.2 -6 L 5.5 0",
+        'L' => "M 0.5 -10 V 0 H 5.5",
+        'M' => "M 0.5 0 V -10 L 3 -4 L 5.5 -10 V 0",
+        'N' => "M 0.5 0 V -10 L 5.5 0 V -10",
+        'O' | '0' => "M 3 -10 C -0.5 -10 -0.5 0 3 0 C 6.5 0 6.5 -10 3 -10 Z",
+        'P' => "M 0.5 0 V -10 H 3 A 2.5 2.5 0 0 1 3 -5 H 0.5",
+        'Q' => "M 3 -10 C -0.5 -10 -0.5 0 3 0 C 6.5 0 6.5 -10 3 -10 Z M 3.5 -2.5 L 5.5 0.5",
+        'R' => "M 0.5 0 V -10 H 3 A 2.5 2.5 0 0 1 3 -5 H 0.5 M 3 -5 L 5.5 0",
+        'S' => {
+            "M 5.2 -8.5 C 4.5 -10.5 0.5 -10.5 0.5 -7.5 C 0.5 -4.5 5.5 -5.5 5.5 -2.5 \
+                C 5.5 0.5 1.5 0.5 0.5 -1.5"
+        }
+        'T' => "M 0.5 -10 H 5.5 M 3 -10 V 0",
+        'U' => "M 0.5 -10 V -3 C 0.5 1 5.5 1 5.5 -3 V -10",
+        'V' => "M 0.5 -10 L 3 0 L 5.5 -10",
+        'W' => "M 0.2 -10 L 1.6 0 L 3 -6 L 4.4 0 L 5.8 -10",
+        'X' => "M 0.5 -10 L 5.5 0 M 5.5 -10 L 0.5 0",
+        'Y' => "M 0.5 -10 L 3 -5 L 5.5 -10 M 3 -5 V 0",
+        'Z' => "M 0.5 -10 H 5.5 L 0.5 0 H 5.5",
+        'a' => "M 5 -7 V 0 M 5 -3.5 C 5 -7.5 0.5 -7.5 0.5 -3.5 C 0.5 0.5 5 0.5 5 -3.5",
+        'b' => "M 0.5 -10 V 0 M 0.5 -3.5 C 0.5 -7.5 5.5 -7.5 5.5 -3.5 C 5.5 0.5 0.5 0.5 0.5 -3.5",
+        'c' => "M 5 -5.8 C 4 -7.5 0.5 -7.5 0.5 -3.5 C 0.5 0.5 4 0.5 5 -1.2",
+        'd' => "M 5 -10 V 0 M 5 -3.5 C 5 -7.5 0.5 -7.5 0.5 -3.5 C 0.5 0.5 5 0.5 5 -3.5",
+        'e' => "M 0.5 -3.5 H 5 C 5 -7.5 0.5 -7.5 0.5 -3.5 C 0.5 0.5 4 0.5 5 -1.2",
+        'f' => "M 4.5 -9.5 C 3.5 -10.5 2 -10 2 -8 V 0 M 0.5 -7 H 4",
+        'g' => {
+            "M 5 -7 V 1 C 5 3.5 1.5 3.5 1 2 M 5 -3.5 C 5 -7.5 0.5 -7.5 0.5 -3.5 \
+                C 0.5 0.5 5 0.5 5 -3.5"
+        }
+        'h' => "M 0.5 -10 V 0 M 0.5 -4.5 C 0.5 -7.5 5 -7.5 5 -4.5 V 0",
+        'i' => "M 3 -7 V 0 M 3 -9.5 V -9.4",
+        'j' => "M 3.5 -7 V 1.5 C 3.5 3.5 1.5 3.5 1 2.5 M 3.5 -9.5 V -9.4",
+        'k' => "M 0.5 -10 V 0 M 4.5 -7 L 0.5 -3 M 2 -4.5 L 5 0",
+        'l' => "M 3 -10 V 0",
+        'm' => {
+            "M 0.5 -7 V 0 M 0.5 -5 C 0.5 -7.5 3 -7.5 3 -5 V 0 \
+                M 3 -5 C 3 -7.5 5.5 -7.5 5.5 -5 V 0"
+        }
+        'n' => "M 0.5 -7 V 0 M 0.5 -4.5 C 0.5 -7.5 5 -7.5 5 -4.5 V 0",
+        'o' => "M 3 -7 C 0 -7 0 0 3 0 C 6 0 6 -7 3 -7 Z",
+        'p' => "M 0.5 -7 V 3 M 0.5 -3.5 C 0.5 -7.5 5.5 -7.5 5.5 -3.5 C 5.5 0.5 0.5 0.5 0.5 -3.5",
+        'q' => "M 5 -7 V 3 M 5 -3.5 C 5 -7.5 0.5 -7.5 0.5 -3.5 C 0.5 0.5 5 0.5 5 -3.5",
+        'r' => "M 1 -7 V 0 M 1 -4 C 1 -7 3.5 -7.5 5 -6.5",
+        's' => "M 4.8 -6 C 4 -7.5 1 -7.5 1 -5.5 C 1 -3.5 5 -3.8 5 -1.7 C 5 0.5 1.5 0.5 0.7 -1",
+        't' => "M 2.5 -9.5 V -1.5 C 2.5 0 4 0.3 5 -0.5 M 0.5 -7 H 4.5",
+        'u' => "M 0.5 -7 V -2.5 C 0.5 0.5 5 0.5 5 -2.5 M 5 -7 V 0",
+        'v' => "M 0.5 -7 L 3 0 L 5.5 -7",
+        'w' => "M 0.2 -7 L 1.6 0 L 3 -5 L 4.4 0 L 5.8 -7",
+        'x' => "M 0.5 -7 L 5.5 0 M 5.5 -7 L 0.5 0",
+        'y' => "M 0.5 -7 L 3 0 M 5.5 -7 L 2 3",
+        'z' => "M 0.5 -7 H 5.5 L 0.5 0 H 5.5",
+        '1' => "M 1.5 -8 L 3.5 -10 V 0 M 1.5 0 H 5.5",
+        '2' => "M 0.8 -8 C 1.5 -10.5 5.5 -10.5 5.2 -7.5 C 5 -5 0.5 -3 0.5 0 H 5.5",
+        '3' => {
+            "M 0.8 -9 C 2 -10.5 5.3 -10.3 5.3 -7.7 C 5.3 -5.5 3 -5.3 2 -5.3 \
+                C 3.5 -5.3 5.5 -4.8 5.5 -2.5 C 5.5 0.5 1.5 0.5 0.5 -1"
+        }
+        '4' => "M 4 0 V -10 L 0.5 -3 H 5.5",
+        '5' => "M 5 -10 H 1 L 0.7 -5.5 C 2 -6.5 5.5 -6.5 5.5 -3 C 5.5 0.5 1.5 0.5 0.5 -1",
+        '6' => {
+            "M 4.8 -9.5 C 3 -10.5 0.5 -9.5 0.5 -4.5 C 0.5 -0.5 1.5 0 3 0 C 4.5 0 5.5 -1 5.5 -3 \
+                C 5.5 -5 4.5 -6 3 -6 C 1.5 -6 0.5 -5 0.5 -3.5"
+        }
+        '7' => "M 0.5 -10 H 5.5 L 2 0",
+        '8' => {
+            "M 3 -5.5 C 0.5 -5.5 0.7 -10 3 -10 C 5.3 -10 5.5 -5.5 3 -5.5 \
+                C 0 -5.5 0.3 0 3 0 C 5.7 0 6 -5.5 3 -5.5 Z"
+        }
+        '9' => {
+            "M 1.2 -0.5 C 3 0.5 5.5 -0.5 5.5 -5.5 C 5.5 -9.5 4.5 -10 3 -10 C 1.5 -10 0.5 -9 0.5 -7 \
+                C 0.5 -5 1.5 -4 3 -4 C 4.5 -4 5.5 -5 5.5 -6.5"
+        }
+        '-' => "M 1 -4 H 5",
+        '.' => "M 3 -0.5 V -0.4",
+        '\'' => "M 3 -10 V -7.5",
+        ' ' => "",
+        // Fortune, which takes the space of a glyph
+        '\u{2297}' => {
+            return Some((
+                "M 9.5 -5 A 4.5 4.5 0 1 1 0.5 -5 A 4.5 4.5 0 1 1 9.5 -5 \
+                 M 1.8 -8.2 L 8.2 -1.8 M 8.2 -8.2 L 1.8 -1.8",
+                11.,
+            ))
+        }
+        _ => return None,
+    };
+    Some((outline, ADVANCE))
+}
+
+/// What a character without an outline is drawn as, like the box of a font
+const MISSING: &str = "M 0.5 -10 H 5.5 V 0 H 0.5 Z";
+
+/// The outlines of the characters of `text` with how far along each one starts, and the width
+/// of the whole. Characters without an outline are drawn as boxes.
+#[must_use]
+pub fn letters(text: &str) -> (Vec<(f32, &'static str)>, f32) {
+    let mut placed = Vec::new();
+    let mut width = 0.;
+    for character in text.chars() {
+        let (outline, advance) = letter(character).unwrap_or((MISSING, ADVANCE));
+        if !outline.is_empty() {
+            placed.push((width, outline));
+        }
+        width += advance;
+    }
+    (placed, width)
+}
+
+/// The scale that makes an outline about as large as a glyph in the default font size
+pub const FONT_SCALE: f32 = 1.1;
+
+/// Half the size of the box the glyphs are drawn in, by which they are moved to centre them
+pub const HALF_WIDTH: f32 = 5.;
+
+/// How the glyphs on the wheel are drawn
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GlyphStyle {
+    /// As text in whichever font the viewer has
+    Font,
+    /// As the outlines in this module, which look the same everywhere
+    Outline,
+}
+
+impl GlyphStyle {
+    pub const ALL: [GlyphStyle; 2] = [GlyphStyle::Outline, GlyphStyle::Font];
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            GlyphStyle::Font => "Font",
+            GlyphStyle::Outline => "Built-in",
+        }
+    }
+}
+
+/// A glyph for use within HTML text, one line high and in the colour of the text
+#[must_use]
+pub fn inline(style: GlyphStyle, character: char, outline: &str) -> Node {
+    match style {
+        GlyphStyle::Font => Node::Text(character.to_string()),
+        GlyphStyle::Outline => Element::new("svg")
+            .attr("viewBox", "-1 -11 12 12")
+            .attr("width", "1em")
+            .attr("height", "1em")
+            .attr("style", "vertical-align: -0.125em")
+            .child(
+                Element::new("path")
+                    .attr("d", outline)
+                    .attr("fill", "none")
+                    .attr("stroke", "currentColor")
+                    .attr("stroke-width", 1)
+                    .attr("stroke-linecap", "round"),
+            )
+            .into(),
+    }
+}
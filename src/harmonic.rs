@@ -0,0 +1,189 @@
+/// The orb within which two bodies count as conjoined in a harmonic chart
+pub const CONJUNCTION_ORB: f32 = 8.;
+
+/// The highest harmonic that can be drawn
+pub const MAX_HARMONIC: f32 = MAX_WHOLE_HARMONIC as f32;
+
+/// `MAX_HARMONIC` as the whole number that the spectrum, lists and sweeps count up to
+pub const MAX_WHOLE_HARMONIC: u16 = 300;
+
+/// Parse a harmonic written as a whole number, a decimal like `1.5` or a fraction like `3/2`
+pub fn parse(text: &str) -> Result<f32, String> {
+    let number = |s: &str| {
+        s.trim()
+            .parse::<f32>()
+            .map_err(|_| format!("Not a number: {:?}", s.trim()))
+    };
+    let mut parts = text.splitn(2, '/');
+    let harmonic = match (parts.next(), parts.next()) {
+        (Some(numerator), Some(denominator)) => number(numerator)? / number(denominator)?,
+        (Some(whole), None) => number(whole)?,
+        _ => unreachable!(),
+    };
+    if harmonic.is_finite() && harmonic > 0. && harmonic <= MAX_HARMONIC {
+        Ok(harmonic)
+    } else {
+        Err(format!(
+            "The harmonic must be above 0 and at most {}",
+            MAX_HARMONIC
+        ))
+    }
+}
+
+/// Format a harmonic without needless decimals
+pub fn describe(harmonic: f32) -> String {
+    let text = format!("{:.4}", harmonic);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// The harmonic as a whole number, if it is one
+pub fn whole(harmonic: f32) -> Option<u16> {
+    if (harmonic - harmonic.round()).abs() < 1e-4
+        && harmonic.round() >= 1.
+        && harmonic <= MAX_HARMONIC
+    {
+        Some(harmonic.round() as u16)
+    } else {
+        None
+    }
+}
+
+/// The ways a chart can be scored for a single harmonic
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Score {
+    /// The length of the mean of the unit vectors of the harmonic positions, as used by
+    /// Addey. It is 1 when every body is in the same place and near 0 when they are spread
+    /// evenly around the circle.
+    Resultant,
+    /// The number of body pairs in conjunction in the harmonic chart
+    Conjunctions,
+}
+
+impl Score {
+    pub fn name(self) -> &'static str {
+        match self {
+            Score::Resultant => "Vector resultant",
+            Score::Conjunctions => "Conjunctions",
+        }
+    }
+}
+
+fn dist(a: f32, b: f32) -> f32 {
+    let d = (a - b).abs() % 360.;
+    d.min(360. - d)
+}
+
+fn resultant(angles: &[f32], harmonic: f32) -> f32 {
+    if angles.is_empty() {
+        return 0.;
+    }
+    let (x, y) = angles.iter().fold((0., 0.), |(x, y), &angle| {
+        let angle = (angle * harmonic).to_radians();
+        (x + angle.cos(), y + angle.sin())
+    });
+    x.hypot(y) / angles.len() as f32
+}
+
+fn conjunction_count(angles: &[f32], harmonic: f32) -> f32 {
+    let mut count = 0;
+    for (i, a) in angles.iter().enumerate() {
+        for b in &angles[i + 1..] {
+            if dist(a * harmonic, b * harmonic) < CONJUNCTION_ORB {
+                count += 1;
+            }
+        }
+    }
+    count as f32
+}
+
+/// Score `angles` for every harmonic from 1 up to and including `max_harmonic`
+pub fn spectrum(angles: &[f32], max_harmonic: u16, score: Score) -> Vec<f32> {
+    (1..=max_harmonic)
+        .map(|harmonic| {
+            let harmonic = f32::from(harmonic);
+            match score {
+                Score::Resultant => resultant(angles, harmonic),
+                Score::Conjunctions => conjunction_count(angles, harmonic),
+            }
+        })
+        .collect()
+}
+
+/// A harmonic in which two bodies are conjoined
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conjunction {
+    /// The index of the first body
+    pub first: usize,
+    /// The index of the second body, always greater than `first`
+    pub second: usize,
+    pub harmonic: u16,
+    /// The distance between the bodies in the harmonic chart
+    pub orb: f32,
+}
+
+/// Find, for every pair of `angles`, the harmonics up to and including `max_harmonic` in
+/// which they are within `orb` of each other
+pub fn find_conjunctions(angles: &[f32], max_harmonic: u16, orb: f32) -> Vec<Conjunction> {
+    let mut found = Vec::new();
+    for (first, a) in angles.iter().enumerate() {
+        for (second, b) in angles.iter().enumerate().skip(first + 1) {
+            for harmonic in 1..=max_harmonic {
+                let distance = dist(a * f32::from(harmonic), b * f32::from(harmonic));
+                if distance < orb {
+                    found.push(Conjunction {
+                        first,
+                        second,
+                        harmonic,
+                        orb: distance,
+                    });
+                }
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_whole_decimal_and_fractional_harmonics() {
+        assert_eq!(parse("5"), Ok(5.));
+        assert_eq!(parse(" 1.5 "), Ok(1.5));
+        assert_eq!(parse("3/2"), Ok(1.5));
+        for bad in &["0", "-2", "301", "1/0", "x", "NaN"] {
+            assert!(parse(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn describes_and_rounds_harmonics() {
+        assert_eq!(describe(7.), "7");
+        assert_eq!(describe(1.25), "1.25");
+        assert_eq!(whole(12.00001), Some(12));
+        assert_eq!(whole(1.5), None);
+        assert_eq!(whole(MAX_HARMONIC + 1.), None);
+    }
+
+    #[test]
+    fn scores_peak_where_the_bodies_line_up() {
+        // Bodies a quarter of the circle apart are together from the fourth harmonic
+        let angles = [0., 90., 180., 270.];
+        let resultant = spectrum(&angles, 8, Score::Resultant);
+        assert_eq!(resultant.len(), 8);
+        assert!(resultant[0] < 1e-4);
+        assert!((resultant[3] - 1.).abs() < 1e-4);
+        assert!((resultant[7] - 1.).abs() < 1e-4);
+        let conjunctions = spectrum(&angles, 4, Score::Conjunctions);
+        assert_eq!(conjunctions, vec![0., 2., 0., 6.]);
+    }
+
+    #[test]
+    fn finds_the_harmonics_that_join_a_pair() {
+        let found = find_conjunctions(&[0., 72.5], 10, 6.);
+        let harmonics: Vec<u16> = found.iter().map(|c| c.harmonic).collect();
+        assert_eq!(harmonics, vec![5, 10]);
+        assert!((found[0].orb - 2.5).abs() < 1e-3);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::app::Positions;
+use crate::date::DateTime;
 use regex::Regex;
 
 pub const SAMPLE: &str = r#"Sun     	22°14'35.78"Cap	  1° 1' 9"	IX
@@ -27,6 +28,9 @@ X	 6°23'53.77"Aqr
 XI	29°48'25.89"Aqr
 XII	18°51'29.91"Ari"#;
 
+/// The birth date of the sample chart
+pub const SAMPLE_BIRTH: &str = "2018-01-12";
+
 #[derive(Debug)]
 struct MatchLine<'a> {
     name: &'a str,
@@ -37,8 +41,12 @@ struct MatchLine<'a> {
 }
 
 lazy_static! {
-    /// A pattern to match lines from ZET9's export format
-    static ref ZET9_PAT: Regex = Regex::new(r#"(\w+)\s+(\d+)°(\d+)'(\d+\.\d+)"(\w+)"#).unwrap();
+    /// A pattern to match lines from ZET9's export format, along with the daily motion where
+    /// there is one
+    static ref ZET9_PAT: Regex = Regex::new(
+        r#"(\w+)\s+(\d+)°(\d+)'(\d+\.\d+)"(\w+)(?:[ \t]+(-)?[ \t]*(\d+)°(?:[ \t]*(\d+)')?(?:[ \t]*(\d+)")?)?"#
+    )
+    .unwrap();
 }
 
 /// The abbreviated zodiac names used by ZET9
@@ -49,6 +57,7 @@ const ZET9_ZODIAC: [&str; 12] = [
 #[derive(Debug)]
 pub enum Error {
     UnknownZodiacSign(String),
+    InvalidDate(String),
 }
 
 fn to_num(ml: &MatchLine) -> Result<f32, Error> {
@@ -63,8 +72,40 @@ fn to_num(ml: &MatchLine) -> Result<f32, Error> {
     Ok(30. * zodiac_idx + deg + (min / 60.) + (sec / 3600.))
 }
 
-pub fn parse_zet9(text: &str) -> Result<Positions, Error> {
+/// Parse an optional birth date, where blank text means that the date is unknown
+///
+/// # Errors
+///
+/// Fails if the text is not in the expected format
+pub fn parse_birth(text: &str) -> Result<Option<DateTime>, Error> {
+    if text.trim().is_empty() {
+        Ok(None)
+    } else {
+        text.parse().map(Some).map_err(Error::InvalidDate)
+    }
+}
+
+/// The daily motion in degrees from the captures of a ZET9 line, if the line has one
+fn speed(caps: &regex::Captures) -> Option<f32> {
+    let number = |i| {
+        caps.get(i)
+            .and_then(|m| m.as_str().parse::<f32>().ok())
+            .unwrap_or(0.)
+    };
+    caps.get(7)?;
+    let speed = number(7) + number(8) / 60. + number(9) / 3600.;
+    Some(if caps.get(6).is_some() { -speed } else { speed })
+}
+
+/// Parse the positions in ZET9's export format, along with the daily motion of the bodies
+/// from the Sun to the Node where it is given
+///
+/// # Errors
+///
+/// Fails if the text is not in the expected format
+pub fn parse_zet9(text: &str) -> Result<(Positions, [Option<f32>; 11]), Error> {
     let mut positions = Positions::default();
+    let mut speeds = [None; 11];
     for caps in ZET9_PAT.captures_iter(text) {
         // All groups must be present for caps to exist
         let ml = MatchLine {
@@ -74,8 +115,12 @@ pub fn parse_zet9(text: &str) -> Result<Positions, Error> {
             seconds: caps.get(4).unwrap().as_str(),
             sign: caps.get(5).unwrap().as_str(),
         };
+        let daily_motion = speed(&caps);
         let mut set_position = |i: usize| {
             positions.0[i] = to_num(&ml)?;
+            if let Some(speed) = speeds.get_mut(i) {
+                *speed = daily_motion;
+            }
             Ok(())
         };
         match ml.name {
@@ -95,5 +140,5 @@ pub fn parse_zet9(text: &str) -> Result<Positions, Error> {
             _ => (),
         }
     }
-    Ok(positions)
+    Ok((positions, speeds))
 }
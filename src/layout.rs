@@ -0,0 +1,162 @@
+//! The geometry of the wheel. All lengths are radii in the units of the drawing, where the
+//! standard wheel is a little over 200 across.
+
+/// How far the position labels reach along the radius
+const LABEL_LENGTH: f32 = 15.;
+
+/// Where the rings of the wheel lie and how large its glyphs are
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layout {
+    /// An extra ring outside the zodiac, as wide as this, for the bodies of a second chart
+    pub outer_ring: f32,
+    pub outer_zodiac: f32,
+    pub inner_zodiac: f32,
+    /// Where the glyphs of the signs stand, or `None` to leave them out
+    pub sign_glyphs: Option<f32>,
+    /// Where the dots marking the exact positions lie, between the zodiac and the degree ring
+    pub markers: f32,
+    /// The width of the ring of degree marks just outside the houses
+    pub degree_ring: f32,
+    /// The degrees between neighbouring marks in the degree ring, or 0 for no marks
+    pub degree_step: u16,
+    pub outer_houses: f32,
+    /// Where the glyphs of the bodies stand, within the houses
+    pub glyphs: f32,
+    /// The size of the glyphs of the bodies, relative to text
+    pub glyph_scale: f32,
+    /// Where the degrees and minutes of each body are written, inside its glyph, or `None`
+    /// to leave them out
+    pub position_labels: Option<f32>,
+    pub inner_houses: f32,
+    /// Where the small rings marking the positions lie, just inside the houses
+    pub inner_markers: f32,
+    pub aspects: f32,
+    /// The disk in the middle, which holds a small horizon in turned charts
+    pub centre: f32,
+}
+
+impl Layout {
+    /// The wheel as it has always been drawn
+    #[must_use]
+    pub fn standard() -> Self {
+        Self {
+            outer_ring: 0.,
+            outer_zodiac: 104.3,
+            inner_zodiac: 93.,
+            sign_glyphs: Some(98.),
+            markers: 90.2,
+            degree_ring: 2.,
+            degree_step: 5,
+            outer_houses: 86.3,
+            glyphs: 77.5,
+            glyph_scale: 0.8,
+            position_labels: None,
+            inner_houses: 70.3,
+            inner_markers: 68.4,
+            aspects: 65.6,
+            centre: 21.9,
+        }
+    }
+
+    /// A thumbnail, with large glyphs and without the fine detail
+    #[must_use]
+    pub fn mini() -> Self {
+        Self {
+            sign_glyphs: None,
+            degree_step: 0,
+            glyph_scale: 1.2,
+            ..Self::standard()
+        }
+    }
+
+    /// A wider degree ring with a mark for every degree
+    #[must_use]
+    pub fn traditional() -> Self {
+        Self {
+            markers: 91.1,
+            degree_ring: 5.,
+            degree_step: 1,
+            outer_houses: 84.3,
+            glyphs: 76.3,
+            inner_houses: 68.3,
+            inner_markers: 66.4,
+            aspects: 63.6,
+            ..Self::standard()
+        }
+    }
+
+    /// An empty ring outside the zodiac, where the bodies of a second chart can go
+    #[must_use]
+    pub fn bi_wheel() -> Self {
+        Self {
+            outer_ring: 14.,
+            ..Self::standard()
+        }
+    }
+
+    /// This layout with the houses widened inwards to make room for the position labels
+    #[must_use]
+    pub fn with_position_labels(self) -> Self {
+        if self.position_labels.is_some() {
+            return self;
+        }
+        Self {
+            position_labels: Some(self.inner_houses - LABEL_LENGTH / 2.),
+            inner_houses: self.inner_houses - LABEL_LENGTH,
+            inner_markers: self.inner_markers - LABEL_LENGTH,
+            aspects: self.aspects - LABEL_LENGTH,
+            centre: self.centre.min((self.aspects - LABEL_LENGTH) / 3.),
+            ..self
+        }
+    }
+
+    /// The radius of the whole drawing, with room for the outermost line
+    #[must_use]
+    pub fn extent(&self) -> f32 {
+        self.outer_zodiac + self.outer_ring + 0.7
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// The ready-made layouts
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChartStyle {
+    Standard,
+    Mini,
+    Traditional,
+    BiWheel,
+}
+
+impl ChartStyle {
+    pub const ALL: [ChartStyle; 4] = [
+        ChartStyle::Standard,
+        ChartStyle::Mini,
+        ChartStyle::Traditional,
+        ChartStyle::BiWheel,
+    ];
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            ChartStyle::Standard => "Standard",
+            ChartStyle::Mini => "Mini-wheel",
+            ChartStyle::Traditional => "Traditional with degree ring",
+            ChartStyle::BiWheel => "With an outer ring for a bi-wheel",
+        }
+    }
+
+    #[must_use]
+    pub fn layout(self) -> Layout {
+        match self {
+            ChartStyle::Standard => Layout::standard(),
+            ChartStyle::Mini => Layout::mini(),
+            ChartStyle::Traditional => Layout::traditional(),
+            ChartStyle::BiWheel => Layout::bi_wheel(),
+        }
+    }
+}
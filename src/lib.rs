@@ -6,22 +6,44 @@
     clippy::default_trait_access,
     clippy::non_ascii_literal
 )]
-#![recursion_limit = "512"]
+#![recursion_limit = "1024"]
 
 #[macro_use]
 extern crate lazy_static;
 
-mod app;
-mod aspect;
+mod antiscia;
+pub mod app;
+pub mod aspect;
+mod colour;
 mod components;
+mod date;
+mod dignities;
+mod dispositors;
+pub mod export;
+pub mod glyphs;
+mod harmonic;
 mod input;
-mod optimize;
+pub mod layout;
+pub mod lots;
+mod midpoint;
+pub mod pdf;
+pub mod placement;
+mod shape;
+pub mod sheet;
+pub mod svg;
+pub mod theme;
+pub mod wheel;
 
 use wasm_bindgen::prelude::*;
 
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
+/// Start the app in the page
+///
+/// # Errors
+///
+/// Never fails, but `wasm_bindgen` start functions return a `Result`
 #[wasm_bindgen]
 pub fn run_app() -> Result<(), JsValue> {
     wasm_logger::init(wasm_logger::Config::default());
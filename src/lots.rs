@@ -0,0 +1,206 @@
+use crate::app::{Positions, NAMES};
+
+/// A term in the formula of a lot
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operand {
+    /// One of the points in `Positions`
+    Point(usize),
+    /// A lot earlier in the table
+    Lot(usize),
+}
+
+/// A lot at ASC + `add` − `subtract`, with the operands swapped in night charts if `reverses`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lot {
+    pub name: String,
+    pub symbol: String,
+    pub add: Operand,
+    pub subtract: Operand,
+    pub reverses: bool,
+}
+
+/// A lot placed in a chart, ready to be drawn or listed like any other point
+#[derive(Clone, Debug, PartialEq)]
+pub struct LotPosition {
+    pub name: String,
+    pub symbol: String,
+    pub position: f32,
+}
+
+const SUN: usize = 0;
+const MOON: usize = 1;
+const VENUS: usize = 3;
+
+/// The number of lots in `standard()`, which later lots may build upon
+pub const STANDARD: usize = 3;
+
+/// Fortune, Spirit and Eros, as given by Valens
+#[must_use]
+pub fn standard() -> Vec<Lot> {
+    vec![
+        Lot {
+            name: "Fortune".to_string(),
+            symbol: "\u{2297}".to_string(),
+            add: Operand::Point(MOON),
+            subtract: Operand::Point(SUN),
+            reverses: true,
+        },
+        Lot {
+            name: "Spirit".to_string(),
+            symbol: "Spi".to_string(),
+            add: Operand::Point(SUN),
+            subtract: Operand::Point(MOON),
+            reverses: true,
+        },
+        Lot {
+            name: "Eros".to_string(),
+            symbol: "Ero".to_string(),
+            add: Operand::Point(VENUS),
+            subtract: Operand::Lot(1),
+            reverses: true,
+        },
+    ]
+}
+
+impl Lot {
+    /// A lot defined by the user, labelled on the wheel by the start of its name
+    #[must_use]
+    pub fn new(name: &str, add: Operand, subtract: Operand, reverses: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            symbol: name.chars().take(3).collect(),
+            add,
+            subtract,
+            reverses,
+        }
+    }
+
+    /// The formula as it applies in a day chart
+    #[must_use]
+    pub fn formula(&self, lots: &[Lot]) -> String {
+        let name = |operand: Operand| match operand {
+            Operand::Point(i) => NAMES.get(i).copied().unwrap_or("?"),
+            Operand::Lot(i) => lots.get(i).map_or("?", |lot| lot.name.as_str()),
+        };
+        format!(
+            "ASC + {} − {}{}",
+            name(self.add),
+            name(self.subtract),
+            if self.reverses {
+                ", reversed at night"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+/// `lots` without the one at `index`, with references to later lots renumbered
+///
+/// # Errors
+///
+/// Fails if another lot is built on the one being removed
+pub fn remove(lots: &[Lot], index: usize) -> Result<Vec<Lot>, String> {
+    let renumber = |operand: Operand| match operand {
+        Operand::Lot(j) if j == index => Err(()),
+        Operand::Lot(j) if j > index => Ok(Operand::Lot(j - 1)),
+        operand => Ok(operand),
+    };
+    let mut kept = Vec::with_capacity(lots.len().saturating_sub(1));
+    for (i, lot) in lots.iter().enumerate() {
+        if i == index {
+            continue;
+        }
+        match (renumber(lot.add), renumber(lot.subtract)) {
+            (Ok(add), Ok(subtract)) => kept.push(Lot {
+                add,
+                subtract,
+                ..lot.clone()
+            }),
+            _ => return Err(format!("{} is built on {}", lot.name, lots[index].name)),
+        }
+    }
+    Ok(kept)
+}
+
+/// Whether the Sun is above the horizon, that is in houses 7 to 12
+#[must_use]
+pub fn is_day(positions: &Positions) -> bool {
+    (positions.sun() - positions.ascendant()).rem_euclid(360.) >= 180.
+}
+
+/// The positions of `lots` in order. A lot may only refer to lots before it, so the list
+/// stops short at the first lot that refers to anything else.
+#[must_use]
+pub fn positions(lots: &[Lot], positions: &Positions) -> Vec<LotPosition> {
+    let day = is_day(positions);
+    let mut placed: Vec<LotPosition> = Vec::with_capacity(lots.len());
+    for lot in lots {
+        let value = |operand: Operand| match operand {
+            Operand::Point(i) => positions.0.get(i).copied(),
+            Operand::Lot(i) => placed.get(i).map(|lot| lot.position),
+        };
+        let (add, subtract) = match (value(lot.add), value(lot.subtract)) {
+            (Some(add), Some(subtract)) if day || !lot.reverses => (add, subtract),
+            (Some(add), Some(subtract)) => (subtract, add),
+            _ => break,
+        };
+        let position = (positions.ascendant() + add - subtract).rem_euclid(360.);
+        placed.push(LotPosition {
+            name: lot.name.clone(),
+            symbol: lot.symbol.clone(),
+            position,
+        });
+    }
+    placed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_lots() -> Vec<Lot> {
+        let mut lots = standard();
+        lots.push(Lot::new("X", Operand::Point(4), Operand::Point(5), false));
+        lots.push(Lot::new("Y", Operand::Point(6), Operand::Point(2), true));
+        lots.push(Lot::new("Z", Operand::Lot(4), Operand::Point(0), true));
+        lots
+    }
+
+    #[test]
+    fn remove_renumbers_later_references() {
+        let lots = user_lots();
+        let removed = remove(&lots, 3).unwrap();
+        assert_eq!(removed.len(), 5);
+        assert_eq!(removed[3].name, "Y");
+        assert_eq!(removed[4].add, Operand::Lot(3));
+        assert_eq!(removed[2].subtract, Operand::Lot(1));
+
+        let chart = Positions([
+            10., 40., 70., 100., 130., 160., 190., 220., 250., 280., 310., 340., 5.,
+        ]);
+        let before = positions(&lots, &chart);
+        let after = positions(&removed, &chart);
+        assert!((after[4].position - before[5].position).abs() < 1e-4);
+    }
+
+    #[test]
+    fn remove_refuses_referenced_lots() {
+        let lots = user_lots();
+        assert!(remove(&lots, 4).is_err());
+        assert!(remove(&lots, 1).is_err());
+        assert_eq!(remove(&lots, 5).unwrap(), lots[..5].to_vec());
+    }
+
+    #[test]
+    fn positions_stop_at_bad_references() {
+        let mut lots = user_lots();
+        lots.push(Lot::new("W", Operand::Lot(9), Operand::Point(1), false));
+        lots.push(Lot::new("V", Operand::Lot(0), Operand::Point(1), false));
+        let chart = Positions([
+            10., 40., 70., 100., 130., 160., 190., 220., 250., 280., 310., 340., 5.,
+        ]);
+        assert_eq!(positions(&lots, &chart).len(), 6);
+        assert_eq!(lots[6].formula(&lots), "ASC + ? − Moon");
+    }
+}
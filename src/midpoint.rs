@@ -0,0 +1,170 @@
+use itertools::Itertools;
+
+/// The moduli midpoints are commonly sorted by, as used on the 360°, 90°, 45° and 22.5° dials
+pub const MODULI: [f32; 4] = [360., 90., 45., 22.5];
+
+/// The midpoint of two points
+#[derive(Clone, Debug, PartialEq)]
+pub struct Midpoint {
+    pub first: usize,
+    pub second: usize,
+    /// The midpoint of the shorter arc between the points
+    pub near: f32,
+    /// The midpoint of the longer arc, opposite `near`
+    pub far: f32,
+}
+
+/// A single point or a midpoint, reduced to a modulus
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortedEntry {
+    pub factor: Factor,
+    pub position: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Factor {
+    Point(usize),
+    Midpoint(usize, usize),
+}
+
+/// A point occupying the midpoint of two others, written `point = first/second`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Picture {
+    pub point: usize,
+    pub first: usize,
+    pub second: usize,
+    /// The distance between the point and the midpoint, measured in the modulus
+    pub orb: f32,
+}
+
+/// The distance between two positions once they are reduced to `modulus`
+pub fn distance(a: f32, b: f32, modulus: f32) -> f32 {
+    let d = (a - b).rem_euclid(modulus);
+    d.min(modulus - d)
+}
+
+/// The midpoints of every pair of `points`
+pub fn midpoints(points: &[f32]) -> Vec<Midpoint> {
+    points
+        .iter()
+        .enumerate()
+        .tuple_combinations()
+        .map(|((first, &a), (second, &b))| {
+            let mut arc = (b - a).rem_euclid(360.);
+            if arc > 180. {
+                arc -= 360.;
+            }
+            let near = (a + arc / 2.).rem_euclid(360.);
+            Midpoint {
+                first,
+                second,
+                near,
+                far: (near + 180.) % 360.,
+            }
+        })
+        .collect()
+}
+
+/// All points and midpoints reduced to `modulus` and sorted by their reduced position
+pub fn sorted(points: &[f32], modulus: f32) -> Vec<SortedEntry> {
+    let singles = points.iter().enumerate().map(|(i, &p)| SortedEntry {
+        factor: Factor::Point(i),
+        position: p.rem_euclid(modulus),
+    });
+    let pairs = midpoints(points).into_iter().map(|m| SortedEntry {
+        factor: Factor::Midpoint(m.first, m.second),
+        position: m.near.rem_euclid(modulus),
+    });
+    let mut entries: Vec<SortedEntry> = singles.chain(pairs).collect();
+    entries.sort_by(|a, b| a.position.partial_cmp(&b.position).unwrap());
+    entries
+}
+
+/// The distance from `position` to the nearer of the two midpoints, reduced to `modulus`
+fn distance_to(midpoint: &Midpoint, position: f32, modulus: f32) -> f32 {
+    distance(position, midpoint.near, modulus).min(distance(position, midpoint.far, modulus))
+}
+
+/// Every midpoint that lies within `orb` of a third point, reduced to `modulus`. With the full
+/// circle both the near and the far midpoint are considered.
+pub fn pictures(points: &[f32], modulus: f32, orb: f32) -> Vec<Picture> {
+    let mut found = Vec::new();
+    for midpoint in midpoints(points) {
+        for (point, &position) in points.iter().enumerate() {
+            if point == midpoint.first || point == midpoint.second {
+                continue;
+            }
+            let distance = distance_to(&midpoint, position, modulus);
+            if distance < orb {
+                found.push(Picture {
+                    point,
+                    first: midpoint.first,
+                    second: midpoint.second,
+                    orb: distance,
+                });
+            }
+        }
+    }
+    found.sort_by(|a, b| a.orb.partial_cmp(&b.orb).unwrap());
+    found
+}
+
+/// The points and midpoints within `orb` of `position`, reduced to `modulus`, nearest first.
+/// As with `pictures`, the far midpoints count on the full circle.
+pub fn at(points: &[f32], position: f32, modulus: f32, orb: f32) -> Vec<Factor> {
+    let singles = points
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| (Factor::Point(i), distance(p, position, modulus)));
+    let pairs = midpoints(points).into_iter().map(|m| {
+        let distance = distance_to(&m, position, modulus);
+        (Factor::Midpoint(m.first, m.second), distance)
+    });
+    singles
+        .chain(pairs)
+        .filter(|&(_, distance)| distance < orb)
+        .sorted_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .map(|(factor, _)| factor)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn midpoints_lie_on_the_shorter_arc() {
+        let found = midpoints(&[350., 20., 100.]);
+        assert_eq!(found.len(), 3);
+        assert!((found[0].near - 5.).abs() < 1e-4);
+        assert!((found[0].far - 185.).abs() < 1e-4);
+        assert!((found[2].near - 60.).abs() < 1e-4);
+        assert!((distance(359., 1., 360.) - 2.).abs() < 1e-4);
+        assert!((distance(89., 181., 90.) - 2.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn sorts_points_and_midpoints_in_the_modulus() {
+        let positions: Vec<f32> = sorted(&[0., 100., 200.], 90.)
+            .iter()
+            .map(|entry| entry.position)
+            .collect();
+        assert_eq!(positions, vec![0., 10., 10., 20., 50., 60.]);
+    }
+
+    #[test]
+    fn the_pointer_reads_the_same_midpoints_as_the_pictures() {
+        // The Moon sits on the far midpoint of the Sun and Mercury
+        let points = [0., 226., 90.];
+        let pictures = pictures(&points, 360., 2.);
+        assert_eq!(pictures.len(), 1);
+        assert_eq!(
+            (pictures[0].point, pictures[0].first, pictures[0].second),
+            (1, 0, 2)
+        );
+        assert_eq!(
+            at(&points, 225., 360., 2.),
+            vec![Factor::Midpoint(0, 2), Factor::Point(1)]
+        );
+    }
+}
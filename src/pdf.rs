@@ -0,0 +1,750 @@
+//! A small PDF writer, with just enough to put vector drawings and text in the standard
+//! Helvetica fonts on a page. It needs nothing from the browser or the system, so the same
+//! file comes out in WASM and natively.
+
+use std::f32::consts::PI;
+use std::fmt::Write;
+
+use crate::colour;
+use crate::svg::{Element, Node};
+
+/// A colour as red, green and blue from 0 to 1
+pub type Rgb = (f32, f32, f32);
+
+pub const BLACK: Rgb = (0., 0., 0.);
+
+/// The way a PDF page is described, in points with the origin in the top left corner and
+/// `y` growing downwards, as in SVG
+pub struct Page {
+    width: f32,
+    height: f32,
+    content: String,
+    /// The fill opacities in use, each of which needs a graphics state in the resources
+    opacities: Vec<f32>,
+}
+
+impl Page {
+    #[must_use]
+    pub fn new(width: f32, height: f32) -> Self {
+        let mut page = Self {
+            width,
+            height,
+            content: String::new(),
+            opacities: Vec::new(),
+        };
+        // PDF measures from the bottom left corner, so turn the page upside down
+        page.op(&format!("1 0 0 -1 0 {} cm", number(height)));
+        page
+    }
+
+    fn op(&mut self, op: &str) {
+        self.content.push_str(op);
+        self.content.push('\n');
+    }
+
+    pub fn save(&mut self) {
+        self.op("q");
+    }
+
+    pub fn restore(&mut self) {
+        self.op("Q");
+    }
+
+    /// Change the coordinate system by the matrix `[a b c d e f]`, as in SVG's `matrix()`
+    pub fn transform(&mut self, matrix: [f32; 6]) {
+        let numbers: Vec<String> = matrix.iter().map(|&n| number(n)).collect();
+        self.op(&format!("{} cm", numbers.join(" ")));
+    }
+
+    pub fn translate(&mut self, x: f32, y: f32) {
+        self.transform([1., 0., 0., 1., x, y]);
+    }
+
+    pub fn scale(&mut self, factor: f32) {
+        self.transform([factor, 0., 0., factor, 0., 0.]);
+    }
+
+    pub fn fill_colour(&mut self, (red, green, blue): Rgb) {
+        self.op(&format!(
+            "{} {} {} rg",
+            number(red),
+            number(green),
+            number(blue)
+        ));
+    }
+
+    pub fn stroke_colour(&mut self, (red, green, blue): Rgb) {
+        self.op(&format!(
+            "{} {} {} RG",
+            number(red),
+            number(green),
+            number(blue)
+        ));
+    }
+
+    pub fn line_width(&mut self, width: f32) {
+        self.op(&format!("{} w", number(width)));
+    }
+
+    fn dash(&mut self, dash: &[f32]) {
+        let numbers: Vec<String> = dash.iter().map(|&n| number(n)).collect();
+        self.op(&format!("[{}] 0 d", numbers.join(" ")));
+    }
+
+    fn round_caps(&mut self) {
+        self.op("1 J");
+    }
+
+    fn fill_opacity(&mut self, opacity: f32) {
+        let index = self
+            .opacities
+            .iter()
+            .position(|&o| (o - opacity).abs() < f32::EPSILON)
+            .unwrap_or_else(|| {
+                self.opacities.push(opacity);
+                self.opacities.len() - 1
+            });
+        self.op(&format!("/GS{} gs", index));
+    }
+
+    pub fn move_to(&mut self, x: f32, y: f32) {
+        self.op(&format!("{} {} m", number(x), number(y)));
+    }
+
+    pub fn line_to(&mut self, x: f32, y: f32) {
+        self.op(&format!("{} {} l", number(x), number(y)));
+    }
+
+    fn curve_to(&mut self, (x1, y1): (f32, f32), (x2, y2): (f32, f32), (x, y): (f32, f32)) {
+        self.op(&format!(
+            "{} {} {} {} {} {} c",
+            number(x1),
+            number(y1),
+            number(x2),
+            number(y2),
+            number(x),
+            number(y)
+        ));
+    }
+
+    pub fn close(&mut self) {
+        self.op("h");
+    }
+
+    pub fn rectangle(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.op(&format!(
+            "{} {} {} {} re",
+            number(x),
+            number(y),
+            number(width),
+            number(height)
+        ));
+    }
+
+    pub fn stroke(&mut self) {
+        self.op("S");
+    }
+
+    pub fn fill(&mut self) {
+        self.op("f");
+    }
+
+    /// Write `text` with its baseline starting at `x`, `y`. Characters that the standard
+    /// fonts don't have come out as question marks.
+    pub fn text(&mut self, x: f32, y: f32, size: f32, bold: bool, text: &str) {
+        let font = if bold { "F2" } else { "F1" };
+        self.op(&format!(
+            "BT /{} {} Tf 1 0 0 -1 {} {} Tm ({}) Tj ET",
+            font,
+            number(size),
+            number(x),
+            number(y),
+            escape(text)
+        ));
+    }
+
+    /// Draw an SVG element and everything in it, in the coordinates of the element
+    pub fn draw_svg(&mut self, element: &Element) {
+        self.draw_element(element, &Style::default());
+    }
+
+    fn draw_element(&mut self, element: &Element, inherited: &Style) {
+        if element.name == "title" || element.name == "metadata" {
+            return;
+        }
+        let mut style = inherited.clone();
+        self.save();
+        for (name, value) in &element.attributes {
+            match *name {
+                "transform" => {
+                    for matrix in parse_transform(value) {
+                        self.transform(matrix);
+                    }
+                }
+                "fill" => style.fill = parse_colour(value).unwrap_or(inherited.fill),
+                "stroke" => style.stroke = parse_colour(value).unwrap_or(inherited.stroke),
+                "stroke-width" => style.stroke_width = value.parse().unwrap_or(1.),
+                "fill-opacity" => style.fill_opacity = value.parse().unwrap_or(1.),
+                "stroke-dasharray" => style.dash = numbers(value),
+                "stroke-linecap" => style.round_caps = value == "round",
+                _ => (),
+            }
+        }
+        let attribute = |name| {
+            element
+                .attributes
+                .iter()
+                .find(|(n, _)| *n == name)
+                .and_then(|(_, value)| value.parse::<f32>().ok())
+                .unwrap_or(0.)
+        };
+        match element.name {
+            "circle" => {
+                self.circle(attribute("cx"), attribute("cy"), attribute("r"));
+                self.paint(&style);
+            }
+            "rect" => {
+                self.rectangle(
+                    attribute("x"),
+                    attribute("y"),
+                    attribute("width"),
+                    attribute("height"),
+                );
+                self.paint(&style);
+            }
+            "path" => {
+                if let Some((_, data)) = element.attributes.iter().find(|(n, _)| *n == "d") {
+                    self.path(data);
+                    self.paint(&style);
+                }
+            }
+            "text" => {
+                let text: String = element
+                    .children
+                    .iter()
+                    .filter_map(|child| match child {
+                        Node::Text(text) => Some(text.as_str()),
+                        Node::Element(_) => None,
+                    })
+                    .collect();
+                if let Some(fill) = style.fill {
+                    self.fill_colour(fill);
+                }
+                let value = |name| {
+                    element
+                        .attributes
+                        .iter()
+                        .find(|(n, _)| *n == name)
+                        .map(|(_, value)| value.as_str())
+                };
+                // The default font size of a browser
+                let size = value("font-size")
+                    .and_then(|size| size.parse().ok())
+                    .unwrap_or(16.);
+                let width = text_width(&text, size);
+                let x = attribute("x")
+                    - match value("text-anchor") {
+                        Some("middle") => width / 2.,
+                        Some("end") => width,
+                        _ => 0.,
+                    };
+                let y = attribute("y")
+                    + if value("dominant-baseline") == Some("central") {
+                        0.35 * size
+                    } else {
+                        0.
+                    };
+                self.text(x, y, size, false, &text);
+            }
+            _ => (),
+        }
+        for child in &element.children {
+            if let Node::Element(child) = child {
+                self.draw_element(child, &style);
+            }
+        }
+        self.restore();
+    }
+
+    fn paint(&mut self, style: &Style) {
+        if let Some(fill) = style.fill {
+            self.fill_colour(fill);
+            if style.fill_opacity < 1. {
+                self.fill_opacity(style.fill_opacity);
+            }
+        }
+        if let Some(stroke) = style.stroke {
+            self.stroke_colour(stroke);
+            self.line_width(style.stroke_width);
+            if !style.dash.is_empty() {
+                self.dash(&style.dash);
+            }
+            if style.round_caps {
+                self.round_caps();
+            }
+        }
+        self.op(match (style.fill, style.stroke) {
+            (Some(_), Some(_)) => "B",
+            (Some(_), None) => "f",
+            (None, Some(_)) => "S",
+            (None, None) => "n",
+        });
+    }
+
+    fn circle(&mut self, cx: f32, cy: f32, r: f32) {
+        // The distance of the control points that makes a quarter circle out of a curve
+        let k = 0.552_285 * r;
+        self.move_to(cx + r, cy);
+        self.curve_to((cx + r, cy + k), (cx + k, cy + r), (cx, cy + r));
+        self.curve_to((cx - k, cy + r), (cx - r, cy + k), (cx - r, cy));
+        self.curve_to((cx - r, cy - k), (cx - k, cy - r), (cx, cy - r));
+        self.curve_to((cx + k, cy - r), (cx + r, cy - k), (cx + r, cy));
+        self.close();
+    }
+
+    /// Trace SVG path data, with quadratic curves and arcs turned into cubic curves
+    fn path(&mut self, data: &str) {
+        let tokens = tokenize(data);
+        let mut tokens = tokens.iter().peekable();
+        let mut current = (0., 0.);
+        let mut start = (0., 0.);
+        let mut command = 'M';
+        while let Some(&token) = tokens.peek() {
+            if let Token::Command(c) = *token {
+                command = c;
+                tokens.next();
+            }
+            let count = match command.to_ascii_uppercase() {
+                'H' | 'V' => 1,
+                'M' | 'L' => 2,
+                'Q' => 4,
+                'C' => 6,
+                'A' => 7,
+                _ => 0,
+            };
+            let mut args = [0_f32; 7];
+            for arg in args.iter_mut().take(count) {
+                match tokens.next() {
+                    Some(Token::Number(n)) => *arg = *n,
+                    _ => return,
+                }
+            }
+            let origin = if command.is_ascii_lowercase() {
+                current
+            } else {
+                (0., 0.)
+            };
+            let point = |i: usize| (origin.0 + args[i], origin.1 + args[i + 1]);
+            match command.to_ascii_uppercase() {
+                'M' => {
+                    current = point(0);
+                    start = current;
+                    self.move_to(current.0, current.1);
+                    // Further pairs of coordinates draw lines
+                    command = if command == 'm' { 'l' } else { 'L' };
+                }
+                'L' => {
+                    current = point(0);
+                    self.line_to(current.0, current.1);
+                }
+                'H' => {
+                    current.0 = origin.0 + args[0];
+                    self.line_to(current.0, current.1);
+                }
+                'V' => {
+                    current.1 = origin.1 + args[0];
+                    self.line_to(current.0, current.1);
+                }
+                'C' => {
+                    let end = point(4);
+                    self.curve_to(point(0), point(2), end);
+                    current = end;
+                }
+                'Q' => {
+                    let control = point(0);
+                    let end = point(2);
+                    let towards = |from: (f32, f32)| {
+                        (
+                            from.0 + 2. / 3. * (control.0 - from.0),
+                            from.1 + 2. / 3. * (control.1 - from.1),
+                        )
+                    };
+                    self.curve_to(towards(current), towards(end), end);
+                    current = end;
+                }
+                'A' => {
+                    let end = point(5);
+                    self.arc(
+                        current,
+                        (args[0], args[1]),
+                        args[2],
+                        args[3] != 0.,
+                        args[4] != 0.,
+                        end,
+                    );
+                    current = end;
+                }
+                command => {
+                    if command == 'Z' {
+                        self.close();
+                        current = start;
+                    }
+                    // Skip the arguments of anything that isn't understood
+                    while let Some(Token::Number(_)) = tokens.peek() {
+                        tokens.next();
+                    }
+                }
+            }
+        }
+    }
+
+    /// An elliptical arc in SVG's endpoint notation, following the conversion in appendix
+    /// B.2.4 of the SVG specification
+    fn arc(
+        &mut self,
+        from: (f32, f32),
+        (rx, ry): (f32, f32),
+        rotation: f32,
+        large: bool,
+        sweep: bool,
+        to: (f32, f32),
+    ) {
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+        if rx == 0. || ry == 0. || from == to {
+            self.line_to(to.0, to.1);
+            return;
+        }
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        let dx = (from.0 - to.0) / 2.;
+        let dy = (from.1 - to.1) / 2.;
+        let x1 = cos * dx + sin * dy;
+        let y1 = -sin * dx + cos * dy;
+        let lambda = (x1 / rx).powi(2) + (y1 / ry).powi(2);
+        if lambda > 1. {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+        let numerator = (rx * ry).powi(2) - (rx * y1).powi(2) - (ry * x1).powi(2);
+        let denominator = (rx * y1).powi(2) + (ry * x1).powi(2);
+        let mut factor = (numerator / denominator).max(0.).sqrt();
+        if large == sweep {
+            factor = -factor;
+        }
+        let cx1 = factor * rx * y1 / ry;
+        let cy1 = -factor * ry * x1 / rx;
+        let cx = cos * cx1 - sin * cy1 + (from.0 + to.0) / 2.;
+        let cy = sin * cx1 + cos * cy1 + (from.1 + to.1) / 2.;
+        let angle = |x: f32, y: f32| y.atan2(x);
+        let start = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+        let mut extent = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry) - start;
+        if sweep && extent < 0. {
+            extent += 2. * PI;
+        } else if !sweep && extent > 0. {
+            extent -= 2. * PI;
+        }
+        // Curves of up to a quarter turn each stay close to the ellipse
+        let segments = (extent.abs() / (PI / 2.)).ceil().max(1.);
+        let step = extent / segments;
+        let k = 4. / 3. * (step / 4.).tan();
+        let on_ellipse = |theta: f32| {
+            let (sin_t, cos_t) = theta.sin_cos();
+            let x = rx * cos_t;
+            let y = ry * sin_t;
+            (cx + cos * x - sin * y, cy + sin * x + cos * y)
+        };
+        let tangent = |theta: f32| {
+            let (sin_t, cos_t) = theta.sin_cos();
+            let x = -rx * sin_t;
+            let y = ry * cos_t;
+            (cos * x - sin * y, sin * x + cos * y)
+        };
+        let mut theta = start;
+        for _ in 0..segments as usize {
+            let next = theta + step;
+            let (p0, p1) = (on_ellipse(theta), on_ellipse(next));
+            let (t0, t1) = (tangent(theta), tangent(next));
+            self.curve_to(
+                (p0.0 + k * t0.0, p0.1 + k * t0.1),
+                (p1.0 - k * t1.0, p1.1 - k * t1.1),
+                p1,
+            );
+            theta = next;
+        }
+    }
+}
+
+/// A PDF file of one or more pages
+pub struct Document {
+    pub title: String,
+    pub pages: Vec<Page>,
+}
+
+impl Document {
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // The catalog, the page tree, the two fonts and the document information come first,
+        // followed by each page and its contents
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                (0..self.pages.len())
+                    .map(|i| format!("{} 0 R", 6 + 2 * i))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                self.pages.len()
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+            format!(
+                "<< /Title ({}) /Producer (chartdraw) >>",
+                escape(&self.title)
+            ),
+        ];
+        for (i, page) in self.pages.iter().enumerate() {
+            let mut states = String::new();
+            for (i, opacity) in page.opacities.iter().enumerate() {
+                let _ = write!(states, "/GS{} << /ca {} >> ", i, number(*opacity));
+            }
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> /ExtGState << {}>> >> \
+                 /Contents {} 0 R >>",
+                number(page.width),
+                number(page.height),
+                states,
+                7 + 2 * i
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{}endstream",
+                page.content.len(),
+                page.content
+            ));
+        }
+
+        let mut out = String::from("%PDF-1.4\n");
+        let mut offsets = Vec::new();
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            let _ = write!(out, "{} 0 obj\n{}\nendobj\n", i + 1, object);
+        }
+        let xref = out.len();
+        let _ = write!(out, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(out, "{:010} 00000 n ", offset);
+        }
+        let _ = write!(
+            out,
+            "trailer\n<< /Size {} /Root 1 0 R /Info 5 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        );
+        out.into_bytes()
+    }
+}
+
+/// The inherited presentation attributes of SVG that matter for drawing
+#[derive(Clone)]
+struct Style {
+    fill: Option<Rgb>,
+    stroke: Option<Rgb>,
+    stroke_width: f32,
+    fill_opacity: f32,
+    dash: Vec<f32>,
+    round_caps: bool,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fill: Some(BLACK),
+            stroke: None,
+            stroke_width: 1.,
+            fill_opacity: 1.,
+            dash: Vec::new(),
+            round_caps: false,
+        }
+    }
+}
+
+/// A colour as SVG writes it. `None` inside stands for no paint at all, and `None` outside for
+/// a colour that isn't understood.
+#[allow(clippy::option_option)]
+fn parse_colour(colour: &str) -> Option<Option<Rgb>> {
+    let channel = |c: u8| f32::from(c) / 255.;
+    colour::parse(colour)
+        .map(|rgb| rgb.map(|(red, green, blue)| (channel(red), channel(green), channel(blue))))
+}
+
+/// The matrices of an SVG transform list, of which `translate`, `rotate` and `scale` are
+/// understood
+fn parse_transform(transform: &str) -> Vec<[f32; 6]> {
+    transform
+        .split(')')
+        .filter_map(|part| {
+            let (name, arguments) = part.split_once('(')?;
+            let arguments = numbers(arguments);
+            let first = arguments.first().copied().unwrap_or(0.);
+            let second = arguments.get(1).copied();
+            match name.trim() {
+                "translate" => Some([1., 0., 0., 1., first, second.unwrap_or(0.)]),
+                "scale" => Some([first, 0., 0., second.unwrap_or(first), 0., 0.]),
+                "rotate" => {
+                    let (sin, cos) = first.to_radians().sin_cos();
+                    Some([cos, sin, -sin, cos, 0., 0.])
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn numbers(list: &str) -> Vec<f32> {
+    list.split(|c: char| c == ',' || c.is_whitespace())
+        .filter_map(|n| n.parse().ok())
+        .collect()
+}
+
+enum Token {
+    Command(char),
+    Number(f32),
+}
+
+fn tokenize(data: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut number = String::new();
+    let flush = |number: &mut String, tokens: &mut Vec<Token>| {
+        if let Ok(n) = number.parse() {
+            tokens.push(Token::Number(n));
+        }
+        number.clear();
+    };
+    for c in data.chars() {
+        match c {
+            // A sign starts a new number unless it belongs to an exponent
+            '-' | '+' if !number.ends_with('e') => {
+                flush(&mut number, &mut tokens);
+                number.push(c);
+            }
+            '0'..='9' | '.' | '-' | '+' | 'e' => number.push(c),
+            c if c.is_ascii_alphabetic() => {
+                flush(&mut number, &mut tokens);
+                tokens.push(Token::Command(c));
+            }
+            _ => flush(&mut number, &mut tokens),
+        }
+    }
+    flush(&mut number, &mut tokens);
+    tokens
+}
+
+/// A number as short as PDF allows
+fn number(n: f32) -> String {
+    let text = format!("{:.3}", n);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// The byte of a character in the Windows encoding of the standard fonts, which agrees with
+/// Latin-1 for the characters used here
+fn win_ansi(c: char) -> Option<u8> {
+    let code = c as u32;
+    if (0x20..=0x7e).contains(&code) || (0xa0..=0xff).contains(&code) {
+        Some(code as u8)
+    } else {
+        None
+    }
+}
+
+/// About how wide `text` comes out in Helvetica. The digits are all this wide, and it is
+/// close enough for the other characters to place short labels.
+fn text_width(text: &str, size: f32) -> f32 {
+    0.556 * size * text.chars().count() as f32
+}
+
+/// Text as the inside of a PDF string, with anything outside ASCII written in octal
+fn escape(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        match win_ansi(c).unwrap_or(b'?') {
+            b'(' => out.push_str("\\("),
+            b')' => out.push_str("\\)"),
+            b'\\' => out.push_str("\\\\"),
+            byte @ 0x20..=0x7e => out.push(byte as char),
+            byte => {
+                let _ = write!(out, "\\{:03o}", byte);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The operators drawn on `page` after the one that turns it upside down
+    fn ops(page: &Page) -> Vec<&str> {
+        page.content.lines().skip(1).collect()
+    }
+
+    #[test]
+    fn xref_offsets_point_at_objects() {
+        let mut first = Page::new(595., 842.);
+        first.text(10., 20., 12., false, "Zürich (radix)");
+        let document = Document {
+            title: "Chart ½".to_string(),
+            pages: vec![first, Page::new(842., 595.)],
+        };
+        let bytes = document.to_bytes();
+        let pdf = String::from_utf8(bytes).expect("a PDF of ASCII");
+
+        let startxref = pdf.rfind("startxref\n").unwrap() + "startxref\n".len();
+        let xref: usize = pdf[startxref..].lines().next().unwrap().parse().unwrap();
+        let mut table = pdf[xref..].lines();
+        assert_eq!(table.next(), Some("xref"));
+        let count: usize = table.next().unwrap()["0 ".len()..].parse().unwrap();
+        assert_eq!(count, 10);
+        assert_eq!(table.next(), Some("0000000000 65535 f "));
+        for object in 1..count {
+            let entry = table.next().unwrap();
+            assert!(entry.ends_with(" 00000 n "), "{}", entry);
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(
+                pdf[offset..].starts_with(&format!("{} 0 obj\n", object)),
+                "object {} is not at {}",
+                object,
+                offset
+            );
+        }
+        assert_eq!(table.next(), Some("trailer"));
+    }
+
+    #[test]
+    fn escape_brackets_backslashes_and_latin_1() {
+        assert_eq!(escape(r"(a) \ b"), r"\(a\) \\ b");
+        assert_eq!(escape("Zürich ½"), r"Z\374rich \275");
+        // Outside Latin-1 there is nothing to write, so the character is marked as missing
+        assert_eq!(escape("☉ é"), r"? \351");
+    }
+
+    #[test]
+    fn path_traces_lines_arcs_and_closes() {
+        let mut page = Page::new(100., 100.);
+        page.path("M 1 2 L 3 4 H 5 V 6 A 2 2 0 0 1 5 10 Z m 1 1 l 2 0 h 1 v 1 z");
+        let ops = ops(&page);
+        assert_eq!(ops[..4], ["1 2 m", "3 4 l", "5 4 l", "5 6 l"]);
+        // The half circle around (5, 8) is drawn as two quarters, through (7, 8)
+        assert_eq!(ops.len(), 12);
+        assert!(ops[4].ends_with(" 7 8 c"), "{}", ops[4]);
+        assert!(ops[5].ends_with(" 5 10 c"), "{}", ops[5]);
+        // Relative coordinates follow on from the start of the closed path
+        assert_eq!(ops[6..], ["h", "2 3 m", "4 3 l", "5 3 l", "5 4 l", "h"]);
+    }
+}
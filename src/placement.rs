@@ -0,0 +1,277 @@
+//! Placement of labels around a circle, such as the glyphs of the bodies on the wheel. Each
+//! label has a place it belongs at and a width along its track, and labels on the same track
+//! are moved apart as little as possible so that none of them overlap.
+//!
+//! The labels of a track are sorted and packed into clusters of touching labels. A cluster sits
+//! where the squared distances of its labels from their places are least, and two clusters that
+//! overlap are merged into one, until no cluster overlaps the next. This gives the same result
+//! for the same labels every time, and as there are fewer merges than labels it is cheap
+//! enough to run on every frame of an animation.
+
+/// A label to be placed around the circle. All angles are in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Label {
+    /// The angle the label belongs at
+    pub target: f32,
+    /// The angle the label takes up along its track
+    pub width: f32,
+    /// Labels keep clear only of those on the same track, so that concentric rings can be
+    /// laid out in one go
+    pub track: usize,
+}
+
+/// The angle taken up by something `size` across at `radius` from the centre
+#[must_use]
+pub fn width(size: f32, radius: f32) -> f32 {
+    (size / radius).to_degrees()
+}
+
+/// Labels packed side by side
+#[derive(Clone, Copy)]
+struct Cluster {
+    /// The first label, as an index into the sorted labels of the track
+    first: usize,
+    len: usize,
+    /// The sum over the labels of their targets less their offsets from the first label
+    sum: f32,
+    /// The offset of the last label from the first
+    span: f32,
+    first_width: f32,
+    last_width: f32,
+}
+
+impl Cluster {
+    fn new(first: usize, target: f32, width: f32) -> Self {
+        Self {
+            first,
+            len: 1,
+            sum: target,
+            span: 0.,
+            first_width: width,
+            last_width: width,
+        }
+    }
+
+    /// Where the first label goes, which puts the labels as close as they can be to their
+    /// targets in the least squares sense
+    fn start(&self) -> f32 {
+        self.sum / self.len as f32
+    }
+
+    fn overlaps(&self, next: &Cluster) -> bool {
+        // A little slack so that labels that just touch are left alone
+        self.start() + self.span + (self.last_width + next.first_width) / 2. > next.start() + 1e-4
+    }
+
+    fn merge(&self, next: &Cluster) -> Self {
+        let shift = self.span + (self.last_width + next.first_width) / 2.;
+        Self {
+            first: self.first,
+            len: self.len + next.len,
+            sum: self.sum + next.sum - shift * next.len as f32,
+            span: shift + next.span,
+            first_width: self.first_width,
+            last_width: next.last_width,
+        }
+    }
+
+    /// The cluster with its targets moved once round the circle
+    fn turned(&self) -> Self {
+        Self {
+            sum: self.sum + 360. * self.len as f32,
+            ..*self
+        }
+    }
+}
+
+/// Where to draw each of `labels`, in the same order. The result for a label is within half
+/// a turn of its target. If the labels of a track are too wide to fit round the circle, they
+/// are narrowed in proportion so that they still do not overlap.
+#[must_use]
+pub fn place(labels: &[Label]) -> Vec<f32> {
+    let mut placed: Vec<f32> = labels.iter().map(|label| label.target).collect();
+    let mut tracks: Vec<usize> = labels.iter().map(|label| label.track).collect();
+    tracks.sort_unstable();
+    tracks.dedup();
+    for track in tracks {
+        let indices: Vec<usize> = (0..labels.len())
+            .filter(|&i| labels[i].track == track)
+            .collect();
+        let track_labels: Vec<Label> = indices.iter().map(|&i| labels[i]).collect();
+        for (i, angle) in indices.into_iter().zip(place_track(&track_labels)) {
+            placed[i] = angle;
+        }
+    }
+    placed
+}
+
+/// Add `cluster` after the others, merging it with those it runs into
+fn push(clusters: &mut Vec<Cluster>, mut cluster: Cluster) {
+    while let Some(last) = clusters.last() {
+        if !last.overlaps(&cluster) {
+            break;
+        }
+        cluster = last.merge(&cluster);
+        clusters.pop();
+    }
+    clusters.push(cluster);
+}
+
+/// Place the labels of a single track
+fn place_track(labels: &[Label]) -> Vec<f32> {
+    let n = labels.len();
+    if n < 2 {
+        return labels.iter().map(|label| label.target).collect();
+    }
+    let total_width: f32 = labels.iter().map(|label| label.width).sum();
+    let narrowing = if total_width > 360. {
+        360. / total_width
+    } else {
+        1.
+    };
+    // Sorted by target, with ties kept in their given order
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| {
+        labels[a]
+            .target
+            .rem_euclid(360.)
+            .partial_cmp(&labels[b].target.rem_euclid(360.))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.cmp(&b))
+    });
+    // Cut the circle open at the widest gap between neighbouring targets, which is where
+    // the clusters are least likely to have to wrap round
+    let targets: Vec<f32> = order
+        .iter()
+        .map(|&i| labels[i].target.rem_euclid(360.))
+        .collect();
+    let gap = |k: usize| (targets[(k + 1) % n] - targets[k]).rem_euclid(360.);
+    let widest = (0..n).fold(0, |widest, k| if gap(k) > gap(widest) { k } else { widest });
+    let unrolled: Vec<f32> = (widest + 1..=widest + n)
+        .map(|k| targets[k % n] + if k < n { 0. } else { 360. })
+        .collect();
+    let order: Vec<usize> = (widest + 1..=widest + n).map(|k| order[k % n]).collect();
+    let widths: Vec<f32> = order.iter().map(|&i| labels[i].width * narrowing).collect();
+
+    let mut clusters: Vec<Cluster> = Vec::with_capacity(n);
+    for k in 0..n {
+        push(&mut clusters, Cluster::new(k, unrolled[k], widths[k]));
+    }
+    // The first cluster may now run into the last one from the other side of the circle
+    while clusters.len() > 1 {
+        let first = clusters[0].turned();
+        if !clusters[clusters.len() - 1].overlaps(&first) {
+            break;
+        }
+        clusters.remove(0);
+        push(&mut clusters, first);
+    }
+
+    let mut placed = vec![0.; n];
+    for cluster in &clusters {
+        let mut angle = cluster.start();
+        for j in 0..cluster.len {
+            let k = (cluster.first + j) % n;
+            if j > 0 {
+                angle += (widths[(k + n - 1) % n] + widths[k]) / 2.;
+            }
+            let target = labels[order[k]].target;
+            placed[order[k]] = target + (angle - target + 180.).rem_euclid(360.) - 180.;
+        }
+    }
+    placed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(target: f32, width: f32, track: usize) -> Label {
+        Label {
+            target,
+            width,
+            track,
+        }
+    }
+
+    fn assert_near(placed: &[f32], expected: &[f32]) {
+        assert_eq!(placed.len(), expected.len());
+        for (p, e) in placed.iter().zip(expected) {
+            assert!((p - e).abs() < 1e-3, "{:?} is not {:?}", placed, expected);
+        }
+    }
+
+    /// The angle between two labels, the short way round
+    fn apart(a: f32, b: f32) -> f32 {
+        let d = (a - b).rem_euclid(360.);
+        d.min(360. - d)
+    }
+
+    #[test]
+    fn labels_that_fit_stay_put() {
+        let labels = [label(10., 8., 0), label(40., 8., 0), label(300., 8., 0)];
+        assert_near(&place(&labels), &[10., 40., 300.]);
+    }
+
+    #[test]
+    fn a_stellium_spreads_around_its_middle() {
+        let labels: Vec<Label> = [101., 100., 104., 102., 103.]
+            .iter()
+            .map(|&target| label(target, 8., 0))
+            .collect();
+        assert_near(&place(&labels), &[94., 86., 118., 102., 110.]);
+    }
+
+    #[test]
+    fn clusters_wrap_round_zero() {
+        let labels = [
+            label(358., 7., 0),
+            label(359., 7., 0),
+            label(1., 7., 0),
+            label(2., 7., 0),
+        ];
+        assert_near(&place(&labels), &[349.5, 356.5, 3.5, 10.5]);
+    }
+
+    #[test]
+    fn overfull_tracks_are_narrowed() {
+        // Twelve labels of 40° need 480° between them, so each gets 30°
+        let labels: Vec<Label> = (0..12).map(|i| label(5. * i as f32, 40., 0)).collect();
+        let placed = place(&labels);
+        for (i, &a) in placed.iter().enumerate() {
+            assert!((a - labels[i].target).abs() <= 180.);
+            for &b in &placed[i + 1..] {
+                assert!(apart(a, b) > 30. - 1e-3, "{:?}", placed);
+            }
+        }
+    }
+
+    #[test]
+    fn tracks_are_laid_out_apart() {
+        let labels = [
+            label(50., 10., 0),
+            label(52., 10., 0),
+            label(50., 4., 1),
+            label(52., 4., 1),
+            label(51., 10., 2),
+        ];
+        assert_near(&place(&labels), &[46., 56., 49., 53., 51.]);
+    }
+
+    #[test]
+    fn placement_is_repeatable() {
+        let labels: Vec<Label> = (0..25)
+            .map(|i| {
+                let i = i as f32;
+                label((i * 7.3) % 50. + 100., 3. + i % 4., i as usize % 2)
+            })
+            .collect();
+        let placed = place(&labels);
+        assert_eq!(placed, place(&labels));
+        // Nor, as no two targets are the same, does it depend on the order of the labels
+        let reversed: Vec<Label> = labels.iter().rev().copied().collect();
+        let mut again = place(&reversed);
+        again.reverse();
+        assert_near(&again, &placed);
+    }
+}
@@ -0,0 +1,229 @@
+//! The distribution of the ten planets round the wheel, with equal houses from the ascendant
+
+use itertools::Itertools;
+
+use crate::app::Positions;
+use crate::dignities;
+
+/// The number of planets that are counted, from the Sun to Pluto
+const PLANETS: usize = 10;
+
+/// Marc Edmund Jones's patterns
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shape {
+    Bundle,
+    Bowl,
+    Bucket,
+    Locomotive,
+    Seesaw,
+    Splash,
+    Splay,
+}
+
+impl Shape {
+    pub fn name(self) -> &'static str {
+        match self {
+            Shape::Bundle => "Bundle",
+            Shape::Bowl => "Bowl",
+            Shape::Bucket => "Bucket",
+            Shape::Locomotive => "Locomotive",
+            Shape::Seesaw => "Seesaw",
+            Shape::Splash => "Splash",
+            Shape::Splay => "Splay",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Shape::Bundle => "All planets within a trine",
+            Shape::Bowl => "All planets within an opposition",
+            Shape::Bucket => "A bowl with a single planet as the handle on the empty side",
+            Shape::Locomotive => "An empty trine, with the planets spread round the rest",
+            Shape::Seesaw => "Two groups facing each other across two empty spaces",
+            Shape::Splash => "Planets spread evenly round the wheel",
+            Shape::Splay => "Irregular clusters of planets",
+        }
+    }
+
+    pub fn classify(positions: &Positions) -> Self {
+        let sorted: Vec<f32> = positions.planets_without_node()[..PLANETS]
+            .iter()
+            .map(|p| p.rem_euclid(360.))
+            .sorted_by(|a, b| a.partial_cmp(b).unwrap())
+            .collect();
+        let gaps = sorted_gaps(&sorted);
+        let largest = gaps[0];
+        if 360. - largest <= 120. {
+            Shape::Bundle
+        } else if 360. - largest <= 180. {
+            Shape::Bowl
+        } else if is_bucket(&sorted) {
+            Shape::Bucket
+        } else if 360. - largest <= 240. {
+            Shape::Locomotive
+        } else if gaps[1] >= 60. {
+            Shape::Seesaw
+        } else if sorted.iter().map(|&p| dignities::sign(p)).unique().count() >= 7 {
+            Shape::Splash
+        } else {
+            Shape::Splay
+        }
+    }
+}
+
+/// The empty arcs between neighbouring planets, largest first
+fn sorted_gaps(sorted: &[f32]) -> Vec<f32> {
+    let mut gaps: Vec<f32> = sorted
+        .iter()
+        .zip(sorted.iter().cycle().skip(1))
+        .map(|(a, b)| (b - a).rem_euclid(360.))
+        .collect();
+    // A single planet, or several at the same place, leave the whole circle empty
+    if gaps.iter().all(|&gap| gap == 0.) {
+        gaps[0] = 360.;
+    }
+    gaps.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    gaps
+}
+
+/// How far the handle of a bucket may stand from the point opposite the middle of the bowl
+const HANDLE_ORB: f32 = 60.;
+
+/// Whether all planets but one fit within an opposition, with that one as a handle on the
+/// empty side, roughly opposite the middle of the others
+fn is_bucket(sorted: &[f32]) -> bool {
+    (0..sorted.len()).any(|handle| {
+        let rest: Vec<f32> = sorted
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != handle)
+            .map(|(_, &p)| p)
+            .collect();
+        let (start, span) = occupied(&rest);
+        let opposite = start + span / 2. + 180.;
+        let distance = (sorted[handle] - opposite + 180.).rem_euclid(360.) - 180.;
+        span <= 180. && distance.abs() <= HANDLE_ORB
+    })
+}
+
+/// Where the arc holding all the planets starts, and how long it is
+fn occupied(sorted: &[f32]) -> (f32, f32) {
+    let (after, gap) = (0..sorted.len())
+        .map(|i| {
+            let after = (i + 1) % sorted.len();
+            (after, (sorted[after] - sorted[i]).rem_euclid(360.))
+        })
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .unwrap_or((0, 0.));
+    // Planets all at the same place take up no room at all
+    if gap == 0. {
+        (sorted[0], 0.)
+    } else {
+        (sorted[after], 360. - gap)
+    }
+}
+
+pub const ELEMENTS: [&str; 4] = ["Fire", "Earth", "Air", "Water"];
+pub const MODALITIES: [&str; 3] = ["Cardinal", "Fixed", "Mutable"];
+
+/// How many planets fall in each element, modality, hemisphere and quadrant
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Balance {
+    pub elements: [usize; 4],
+    pub modalities: [usize; 3],
+    /// Houses 7 to 12
+    pub above: usize,
+    /// Houses 1 to 6
+    pub below: usize,
+    /// Houses 10 to 3
+    pub east: usize,
+    /// Houses 4 to 9
+    pub west: usize,
+    /// Houses 1 to 3, 4 to 6, 7 to 9 and 10 to 12
+    pub quadrants: [usize; 4],
+}
+
+impl Balance {
+    pub fn new(positions: &Positions) -> Self {
+        let mut balance = Self::default();
+        for &position in &positions.planets_without_node()[..PLANETS] {
+            let sign = dignities::sign(position);
+            balance.elements[sign % 4] += 1;
+            balance.modalities[sign % 3] += 1;
+            let house = house(position, positions.ascendant());
+            if house >= 6 {
+                balance.above += 1;
+            } else {
+                balance.below += 1;
+            }
+            if (3..9).contains(&house) {
+                balance.west += 1;
+            } else {
+                balance.east += 1;
+            }
+            balance.quadrants[house / 3] += 1;
+        }
+        balance
+    }
+}
+
+/// The equal house a position is in, counting from 0 for the first house
+pub fn house(position: f32, ascendant: f32) -> usize {
+    ((position - ascendant).rem_euclid(360.) / 30.) as usize % 12
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A chart with the ten planets at `planets` and the ascendant at 0°
+    fn chart(planets: [f32; 10]) -> Positions {
+        let mut positions = [0.; 13];
+        positions[..10].copy_from_slice(&planets);
+        Positions(positions)
+    }
+
+    #[test]
+    fn bundles_and_bowls_fit_in_a_trine_and_an_opposition() {
+        let bundle = chart([0., 10., 20., 30., 40., 50., 60., 70., 80., 110.]);
+        assert_eq!(Shape::classify(&bundle), Shape::Bundle);
+        let bowl = chart([0., 20., 40., 60., 80., 100., 120., 140., 160., 175.]);
+        assert_eq!(Shape::classify(&bowl), Shape::Bowl);
+    }
+
+    #[test]
+    fn a_bucket_has_its_handle_opposite_the_bowl() {
+        let bucket = chart([0., 20., 40., 60., 80., 100., 120., 140., 160., 260.]);
+        assert_eq!(Shape::classify(&bucket), Shape::Bucket);
+        // Wrapping round 0° changes nothing
+        let turned = chart([300., 320., 340., 0., 20., 40., 60., 80., 100., 200.]);
+        assert_eq!(Shape::classify(&turned), Shape::Bucket);
+    }
+
+    #[test]
+    fn a_planet_beside_the_bowl_is_no_handle() {
+        let locomotive = chart([0., 20., 40., 60., 80., 100., 120., 140., 160., 185.]);
+        assert_eq!(Shape::classify(&locomotive), Shape::Locomotive);
+    }
+
+    #[test]
+    fn seesaws_splashes_and_splays() {
+        let seesaw = chart([0., 20., 40., 60., 80., 180., 200., 220., 240., 260.]);
+        assert_eq!(Shape::classify(&seesaw), Shape::Seesaw);
+        let splash = chart([0., 36., 72., 108., 144., 180., 216., 252., 288., 324.]);
+        assert_eq!(Shape::classify(&splash), Shape::Splash);
+        let splay = chart([0., 5., 10., 50., 55., 100., 105., 150., 200., 250.]);
+        assert_eq!(Shape::classify(&splay), Shape::Splay);
+    }
+
+    #[test]
+    fn balance_counts_elements_and_houses() {
+        let balance = Balance::new(&chart([
+            0., 30., 60., 90., 120., 150., 180., 210., 240., 270.,
+        ]));
+        assert_eq!(balance.elements, [3, 3, 2, 2]);
+        assert_eq!(balance.modalities, [4, 3, 3]);
+        assert_eq!(balance.above + balance.below, 10);
+        assert_eq!(balance.quadrants, [3, 3, 3, 1]);
+    }
+}
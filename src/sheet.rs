@@ -0,0 +1,173 @@
+//! A printable page with the wheel next to the positions, an aspect grid and the chart data
+
+use itertools::Itertools;
+
+use crate::app::truncate_angle;
+use crate::aspect;
+use crate::export::Metadata;
+use crate::glyphs::{self, GlyphStyle};
+use crate::pdf::{self, Document, Page, BLACK};
+use crate::svg::Element;
+use crate::wheel::Wheel;
+
+/// The space left around the edge of the page, in points
+const MARGIN: f32 = 40.;
+/// The share of the width between the margins taken by the wheel
+const WHEEL_SHARE: f32 = 0.62;
+const ROW: f32 = 13.;
+const CELL: f32 = 24.;
+const GREY: pdf::Rgb = (0.5, 0.5, 0.5);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Paper {
+    A4,
+    Letter,
+}
+
+impl Paper {
+    pub const ALL: [Paper; 2] = [Paper::A4, Paper::Letter];
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Paper::A4 => "A4",
+            Paper::Letter => "Letter",
+        }
+    }
+
+    /// The width and height of a portrait page in points
+    fn size(self) -> (f32, f32) {
+        match self {
+            Paper::A4 => (595.28, 841.89),
+            Paper::Letter => (612., 792.),
+        }
+    }
+}
+
+/// The chart on one page as a PDF file
+#[must_use]
+pub fn chart_sheet(wheel: &Wheel, metadata: &Metadata, paper: Paper) -> Vec<u8> {
+    let (width, height) = paper.size();
+    let mut page = Page::new(width, height);
+    page.fill_colour(BLACK);
+    page.text(MARGIN, MARGIN + 14., 18., true, &metadata.title);
+
+    let top = MARGIN + 36.;
+    let size = (width - 2. * MARGIN) * WHEEL_SHARE;
+    draw_wheel(&mut page, wheel, MARGIN, top, size);
+    positions(&mut page, &metadata.points, MARGIN + size + 20., top);
+    let bodies = (wheel.planets as usize + 2).min(10);
+    let grid_top = top + size + 30.;
+    aspect_grid(&mut page, wheel, bodies, MARGIN, grid_top);
+    chart_data(
+        &mut page,
+        &metadata.settings,
+        MARGIN + CELL * bodies as f32 + 30.,
+        grid_top,
+    );
+
+    Document {
+        title: metadata.title.clone(),
+        pages: vec![page],
+    }
+    .to_bytes()
+}
+
+/// The wheel in a square of `size` points with its top left corner at `x`, `y`
+fn draw_wheel(page: &mut Page, wheel: &Wheel, x: f32, y: f32, size: f32) {
+    // The fonts of a PDF viewer don't have the astrological glyphs, so everything on the
+    // wheel is drawn as outlines
+    let wheel = Wheel {
+        glyph_style: GlyphStyle::Outline,
+        interactive: false,
+        ..*wheel
+    };
+    page.save();
+    page.translate(x + size / 2., y + size / 2.);
+    page.scale(size / (2. * wheel.layout.extent()));
+    page.draw_svg(&wheel.render());
+    page.restore();
+}
+
+/// The radix positions, as in the list next to the chart on screen
+fn positions(page: &mut Page, points: &[(&str, f32)], x: f32, y: f32) {
+    page.fill_colour(BLACK);
+    page.text(x, y + 10., 11., true, "Positions");
+    for (i, (name, longitude)) in points.iter().enumerate() {
+        let baseline = y + 10. + ROW * (i + 1) as f32;
+        page.text(x, baseline, 9., false, name);
+        page.text(x + 62., baseline, 9., false, &truncate_angle(*longitude));
+    }
+}
+
+/// A staircase of the aspects between the first `bodies` bodies, as drawn in the wheel, with
+/// the glyphs of the bodies along the diagonal
+fn aspect_grid(page: &mut Page, wheel: &Wheel, bodies: usize, x: f32, y: f32) {
+    page.fill_colour(BLACK);
+    page.text(x, y + 10., 11., true, "Aspects");
+    let top = y + 18.;
+    let positions = wheel.positions.planets_without_node();
+    page.stroke_colour(GREY);
+    page.line_width(0.5);
+    for i in 0..bodies {
+        let row = top + CELL * i as f32;
+        for j in 0..=i {
+            page.rectangle(x + CELL * j as f32, row, CELL, CELL);
+        }
+    }
+    page.stroke();
+
+    for (i, body) in glyphs::BODIES.iter().take(bodies).enumerate() {
+        let row = top + CELL * i as f32;
+        outline(page, body, x + CELL * i as f32 + 6., row + 17., 1.2);
+    }
+    for (i, j) in (0..bodies).tuple_combinations() {
+        let aspect = match aspect::aspect(positions[i], positions[j], wheel.orb) {
+            Some(aspect) if wheel.aspect_types.contains(aspect.aspect_type) => aspect,
+            _ => continue,
+        };
+        let (left, row) = (x + CELL * i as f32, top + CELL * j as f32);
+        outline(
+            page,
+            glyphs::ASPECTS[aspect.aspect_type as usize],
+            left + 3.,
+            row + 11.,
+            0.8,
+        );
+        let minutes = (aspect.orb * 60.).round() as u32;
+        page.text(
+            left + 3.,
+            row + 19.,
+            6.,
+            false,
+            &format!("{}°{:02}'", minutes / 60, minutes % 60),
+        );
+    }
+}
+
+/// One of the glyph outlines, standing on the baseline at `x`, `y` and `10 * scale` points high
+fn outline(page: &mut Page, outline: &str, x: f32, y: f32, scale: f32) {
+    page.save();
+    page.translate(x, y);
+    page.scale(scale);
+    page.draw_svg(
+        &Element::new("path")
+            .attr("d", outline)
+            .attr("fill", "none")
+            .attr("stroke", "black")
+            .attr("stroke-width", 0.8)
+            .attr("stroke-linecap", "round"),
+    );
+    page.restore();
+}
+
+/// The settings of the drawing and what is known about the chart
+fn chart_data(page: &mut Page, settings: &[(&'static str, String)], x: f32, y: f32) {
+    page.fill_colour(BLACK);
+    page.text(x, y + 10., 11., true, "Chart data");
+    for (i, (name, value)) in settings.iter().enumerate() {
+        let baseline = y + 10. + ROW * (i + 1) as f32;
+        page.text(x, baseline, 8., true, name);
+        page.text(x + 70., baseline, 8., false, value);
+    }
+}
@@ -0,0 +1,121 @@
+//! A minimal SVG document tree, which can be written out as text or turned into yew nodes
+
+use std::fmt::Display;
+use yew::virtual_dom::{VNode, VTag, VText};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Element {
+    pub name: &'static str,
+    pub attributes: Vec<(&'static str, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    #[must_use]
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            attributes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn attr(mut self, name: &'static str, value: impl Display) -> Self {
+        self.attributes.push((name, value.to_string()));
+        self
+    }
+
+    #[must_use]
+    pub fn child(mut self, child: impl Into<Node>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Add any number of children, which also takes an `Option` for a child that may be left out
+    #[must_use]
+    pub fn children<N: Into<Node>>(mut self, children: impl IntoIterator<Item = N>) -> Self {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    #[must_use]
+    pub fn text(self, text: impl Display) -> Self {
+        self.child(Node::Text(text.to_string()))
+    }
+}
+
+impl From<Element> for Node {
+    fn from(element: Element) -> Self {
+        Node::Element(element)
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Element {
+    /// The element and everything in it as SVG text
+    #[must_use]
+    pub fn to_xml(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out);
+        out
+    }
+
+    fn write(&self, out: &mut String) {
+        out.push('<');
+        out.push_str(self.name);
+        for (name, value) in &self.attributes {
+            out.push(' ');
+            out.push_str(name);
+            out.push_str("=\"");
+            out.push_str(&escape(value));
+            out.push('"');
+        }
+        if self.children.is_empty() {
+            out.push_str("/>");
+            return;
+        }
+        out.push('>');
+        for child in &self.children {
+            match child {
+                Node::Element(element) => element.write(out),
+                Node::Text(text) => out.push_str(&escape(text)),
+            }
+        }
+        out.push_str("</");
+        out.push_str(self.name);
+        out.push('>');
+    }
+}
+
+impl From<Node> for VNode {
+    fn from(node: Node) -> Self {
+        match node {
+            Node::Element(element) => element.into(),
+            Node::Text(text) => VText::new(text).into(),
+        }
+    }
+}
+
+impl From<Element> for VNode {
+    fn from(element: Element) -> Self {
+        let mut tag = VTag::new(element.name);
+        for (name, value) in &element.attributes {
+            tag.add_attribute(name, value);
+        }
+        tag.add_children(element.children.into_iter().map(Into::into).collect());
+        tag.into()
+    }
+}
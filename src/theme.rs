@@ -0,0 +1,313 @@
+//! The colours of the wheel. Colours are written as in SVG, so a theme can use hex codes,
+//! `rgb()`, colour names or `none`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::colour;
+
+/// Colours left out of a saved theme are those of the classic one
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub name: String,
+    /// Behind the whole wheel
+    pub background: String,
+    /// The zodiac ring and the glyphs of the signs
+    pub zodiac: String,
+    /// The ring of the houses, where the bodies are
+    pub houses: String,
+    /// The inner ring above the horizon
+    pub above_horizon: String,
+    /// The inner ring below the horizon
+    pub below_horizon: String,
+    /// The house cusps, the horizon, the five degree marks and the rims of the rings
+    pub lines: String,
+    /// The glyphs of the bodies and lots
+    pub bodies: String,
+    /// The dots that mark the exact positions
+    pub markers: String,
+    /// The bands linking the glyphs of conjoined bodies
+    pub conjunction_bands: String,
+    pub antiscia: String,
+    pub contra_antiscia: String,
+    /// The disk the aspects are drawn on
+    pub aspect_background: String,
+    pub conjunctions: String,
+    /// Squares and oppositions
+    pub hard_aspects: String,
+    /// Semi-sextiles, sextiles and trines
+    pub soft_aspects: String,
+    /// The disk in the centre of the wheel
+    pub centre: String,
+    /// The outlines in the centre and the descendant arrow
+    pub outline: String,
+    /// The Sun and Moon on the small horizon of a turned chart
+    pub sun: String,
+    pub moon: String,
+}
+
+impl Theme {
+    /// The original look, after ZET9
+    #[must_use]
+    pub fn classic() -> Self {
+        Self {
+            name: "ZET9 classic".to_string(),
+            background: "none".to_string(),
+            zodiac: "#00aaaa".to_string(),
+            houses: "#55ffff".to_string(),
+            above_horizon: "#5555ff".to_string(),
+            below_horizon: "#0000aa".to_string(),
+            lines: "white".to_string(),
+            bodies: "black".to_string(),
+            markers: "white".to_string(),
+            conjunction_bands: "#ffff55".to_string(),
+            antiscia: "white".to_string(),
+            contra_antiscia: "#ffff55".to_string(),
+            aspect_background: "white".to_string(),
+            conjunctions: "#aa5500".to_string(),
+            hard_aspects: "#aa0000".to_string(),
+            soft_aspects: "#00aa00".to_string(),
+            centre: "#55ffff".to_string(),
+            outline: "black".to_string(),
+            sun: "yellow".to_string(),
+            moon: "lightgrey".to_string(),
+        }
+    }
+
+    /// Pale fills that save ink on paper
+    #[must_use]
+    pub fn light_print() -> Self {
+        Self {
+            name: "Light print".to_string(),
+            background: "white".to_string(),
+            zodiac: "#333333".to_string(),
+            houses: "#f2f2f2".to_string(),
+            above_horizon: "#ffffff".to_string(),
+            below_horizon: "#e0e0e0".to_string(),
+            lines: "#888888".to_string(),
+            bodies: "black".to_string(),
+            markers: "#555555".to_string(),
+            conjunction_bands: "#ffe680".to_string(),
+            antiscia: "#555555".to_string(),
+            contra_antiscia: "#c08000".to_string(),
+            aspect_background: "white".to_string(),
+            conjunctions: "#b36b00".to_string(),
+            hard_aspects: "#cc0000".to_string(),
+            soft_aspects: "#0066cc".to_string(),
+            centre: "#f2f2f2".to_string(),
+            outline: "black".to_string(),
+            sun: "#f0c000".to_string(),
+            moon: "#c0c0c0".to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn dark() -> Self {
+        Self {
+            name: "Dark".to_string(),
+            background: "#121212".to_string(),
+            zodiac: "#4fc3c3".to_string(),
+            houses: "#1e3a4a".to_string(),
+            above_horizon: "#26324d".to_string(),
+            below_horizon: "#0d1526".to_string(),
+            lines: "#9aa5b1".to_string(),
+            bodies: "#f0f0f0".to_string(),
+            markers: "#f0f0f0".to_string(),
+            conjunction_bands: "#806a00".to_string(),
+            antiscia: "#f0f0f0".to_string(),
+            contra_antiscia: "#ffd54f".to_string(),
+            aspect_background: "#1a1a1a".to_string(),
+            conjunctions: "#ff9800".to_string(),
+            hard_aspects: "#ef5350".to_string(),
+            soft_aspects: "#66bb6a".to_string(),
+            centre: "#1e3a4a".to_string(),
+            outline: "#f0f0f0".to_string(),
+            sun: "#ffd54f".to_string(),
+            moon: "#b0b0b0".to_string(),
+        }
+    }
+
+    /// Strong colours and black lines, for poor eyesight and bright screens
+    #[must_use]
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "High contrast".to_string(),
+            background: "white".to_string(),
+            zodiac: "black".to_string(),
+            houses: "#ffff00".to_string(),
+            above_horizon: "#00ffff".to_string(),
+            below_horizon: "#0000ff".to_string(),
+            lines: "black".to_string(),
+            bodies: "black".to_string(),
+            markers: "black".to_string(),
+            conjunction_bands: "#ff00ff".to_string(),
+            antiscia: "black".to_string(),
+            contra_antiscia: "#ff00ff".to_string(),
+            aspect_background: "white".to_string(),
+            conjunctions: "#ff6600".to_string(),
+            hard_aspects: "#ff0000".to_string(),
+            soft_aspects: "#008000".to_string(),
+            centre: "#ffff00".to_string(),
+            outline: "black".to_string(),
+            sun: "#ffff00".to_string(),
+            moon: "white".to_string(),
+        }
+    }
+
+    /// Shades of grey, for black and white printers
+    #[must_use]
+    pub fn greyscale() -> Self {
+        Self {
+            name: "Greyscale".to_string(),
+            background: "white".to_string(),
+            zodiac: "#404040".to_string(),
+            houses: "#d9d9d9".to_string(),
+            above_horizon: "#bfbfbf".to_string(),
+            below_horizon: "#808080".to_string(),
+            lines: "white".to_string(),
+            bodies: "black".to_string(),
+            markers: "white".to_string(),
+            conjunction_bands: "#f2f2f2".to_string(),
+            antiscia: "white".to_string(),
+            contra_antiscia: "#404040".to_string(),
+            aspect_background: "white".to_string(),
+            conjunctions: "#404040".to_string(),
+            hard_aspects: "black".to_string(),
+            soft_aspects: "#808080".to_string(),
+            centre: "#d9d9d9".to_string(),
+            outline: "black".to_string(),
+            sun: "white".to_string(),
+            moon: "#a0a0a0".to_string(),
+        }
+    }
+
+    /// The themes that come with the program, the classic one first
+    #[must_use]
+    pub fn built_in() -> Vec<Theme> {
+        vec![
+            Theme::classic(),
+            Theme::light_print(),
+            Theme::dark(),
+            Theme::high_contrast(),
+            Theme::greyscale(),
+        ]
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        // A struct of strings always serializes
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// A theme saved by `to_json`
+    ///
+    /// # Errors
+    ///
+    /// Fails if `json` does not describe a theme, has no name or has a colour that isn't
+    /// understood
+    pub fn from_json(json: &str) -> Result<Theme, String> {
+        let not_a_theme = |err: serde_json::Error| format!("This is not a theme: {}", err);
+        let mut value: Value = serde_json::from_str(json).map_err(not_a_theme)?;
+        // Without its own name the theme would take the place of the classic one
+        match value.get("name").and_then(Value::as_str) {
+            Some(name) if !name.trim().is_empty() => {}
+            _ => return Err("The theme needs a name".to_string()),
+        }
+        // The colours are kept the way `colour::parse` reads them, so "None" is taken as none
+        if let Some(fields) = value.as_object_mut() {
+            for (_, field) in fields.iter_mut().filter(|(key, _)| *key != "name") {
+                if let Value::String(colour) = field {
+                    *colour = colour.trim().to_ascii_lowercase();
+                }
+            }
+        }
+        let theme: Theme = serde_json::from_value(value).map_err(not_a_theme)?;
+        match theme
+            .colours()
+            .iter()
+            .find(|(_, colour)| colour::parse(colour).is_none())
+        {
+            Some((part, colour)) => Err(format!(
+                "The colour of {} is not understood: {}",
+                part, colour
+            )),
+            None => Ok(theme),
+        }
+    }
+
+    /// Every colour with the name it is saved under
+    fn colours(&self) -> [(&'static str, &str); 19] {
+        [
+            ("background", &self.background),
+            ("zodiac", &self.zodiac),
+            ("houses", &self.houses),
+            ("above_horizon", &self.above_horizon),
+            ("below_horizon", &self.below_horizon),
+            ("lines", &self.lines),
+            ("bodies", &self.bodies),
+            ("markers", &self.markers),
+            ("conjunction_bands", &self.conjunction_bands),
+            ("antiscia", &self.antiscia),
+            ("contra_antiscia", &self.contra_antiscia),
+            ("aspect_background", &self.aspect_background),
+            ("conjunctions", &self.conjunctions),
+            ("hard_aspects", &self.hard_aspects),
+            ("soft_aspects", &self.soft_aspects),
+            ("centre", &self.centre),
+            ("outline", &self.outline),
+            ("sun", &self.sun),
+            ("moon", &self.moon),
+        ]
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::classic()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_themes_round_trip() {
+        for theme in Theme::built_in() {
+            assert_eq!(Theme::from_json(&theme.to_json()), Ok(theme));
+        }
+    }
+
+    #[test]
+    fn missing_colours_are_classic() {
+        let theme = Theme::from_json(r#"{ "name": "Navy", "houses": "navy" }"#).unwrap();
+        assert_eq!(theme.houses, "navy");
+        assert_eq!(theme.zodiac, Theme::classic().zodiac);
+        assert_eq!(theme.moon, Theme::classic().moon);
+    }
+
+    #[test]
+    fn unknown_colours_are_refused() {
+        let err =
+            Theme::from_json(r#"{ "name": "Grey", "lines": "hsl(0, 0%, 50%)" }"#).unwrap_err();
+        assert!(err.contains("lines"), "{}", err);
+        assert!(Theme::from_json(r#"{ "name": "Navy", "lines": "rgb(0, 0, 128)" }"#).is_ok());
+    }
+
+    #[test]
+    fn themes_need_a_name() {
+        assert!(Theme::from_json(r#"{ "lines": "navy" }"#).is_err());
+        assert!(Theme::from_json(r#"{ "name": " ", "lines": "navy" }"#).is_err());
+    }
+
+    #[test]
+    fn colours_are_normalised() {
+        let theme =
+            Theme::from_json(r#"{ "name": "Plain", "background": " None ", "lines": "Navy" }"#)
+                .unwrap();
+        assert_eq!(theme.background, "none");
+        assert_eq!(theme.lines, "navy");
+        assert_eq!(theme.name, "Plain");
+    }
+}
@@ -0,0 +1,1112 @@
+//! The chart wheel as an SVG document, drawn without a browser so that it can also be
+//! rendered natively
+
+use itertools::Itertools;
+use std::fmt::Display;
+
+use crate::antiscia::Reflection;
+use crate::app::{self, truncate_angle, Positions, NAMES};
+use crate::aspect;
+use crate::colour;
+use crate::glyphs::{self, GlyphStyle};
+use crate::layout::Layout;
+use crate::lots::LotPosition;
+use crate::placement::{self, Label};
+use crate::svg::Element;
+use crate::theme::Theme;
+
+const ZODIAC_GLYPHS: [char; 12] = [
+    '♈', '♉', '♊', '♋', '♌', '♍', '♎', '♏', '♐', '♑', '♒', '♓',
+];
+const PLANET_GLYPHS: [char; 11] = ['☉', '☽', '☿', '♀', '♂', '♃', '♄', '♅', '♆', '♇', '☊'];
+
+/// The size of a glyph drawn at a scale of 1, which is the font size of the wheel
+const GLYPH_SIZE: f32 = 12.;
+/// The font size of the position labels
+const LABEL_SIZE: f32 = 4.;
+
+/// The opacity of what is not in focus
+const DIMMED: f32 = 0.25;
+
+/// The radius the small horizon in the centre of turned charts is drawn at, before it is
+/// scaled to the centre disk
+const MINI_HORIZON: f32 = 21.9;
+
+/// Everything that decides how the wheel is drawn
+#[derive(Clone, Copy)]
+pub struct Wheel<'a> {
+    pub positions: &'a Positions,
+    /// The houses are turned so that the cusp of house `cycle + 1` lies on the horizon
+    pub cycle: u16,
+    pub aspect: bool,
+    pub aspect_types: aspect::Selection,
+    /// The orb of the main aspects in the drawn chart
+    pub orb: f32,
+    pub planets: u16,
+    /// The sizes of the rings
+    pub layout: Layout,
+    /// Mark the antiscia and contra-antiscia of the displayed bodies
+    pub antiscia: bool,
+    /// Lots drawn alongside the bodies
+    pub lots: &'a [LotPosition],
+    /// The daily motion of the bodies from the Sun on, where it is known. The ones moving
+    /// backwards get a retrograde mark.
+    pub speeds: &'a [Option<f32>],
+    pub glyph_style: GlyphStyle,
+    pub theme: &'a Theme,
+    /// The body or lot, by its place in the markers, whose aspects stand out while the rest
+    /// of the drawing is dimmed
+    pub focus: Option<usize>,
+    /// Give the bodies, aspects and cusps tooltips and areas to point at, and mark which body
+    /// each marker belongs to. Only the wheel on the page needs these, not a saved file.
+    pub interactive: bool,
+}
+
+impl Wheel<'_> {
+    /// The wheel as a standalone SVG document
+    #[must_use]
+    pub fn to_svg(&self) -> String {
+        self.render().to_xml()
+    }
+
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn render(&self) -> Element {
+        let layout = self.layout;
+        let zodiac_start = self.zodiac_start();
+        let cycle_offset = self.cycle_offset();
+        let (positions, glyph_positions) = self.marker_positions();
+        let extent = layout.extent();
+        let centre = if self.cycle == 0 {
+            Element::new("g")
+                .child(
+                    Element::new("circle")
+                        .attr("r", layout.centre)
+                        .attr("stroke", &self.theme.outline)
+                        .attr("fill", &self.theme.centre),
+                )
+                .child(
+                    Element::new("circle")
+                        .attr("r", 0.6)
+                        .attr("fill", &self.theme.outline),
+                )
+        } else {
+            self.mini_horizon()
+        };
+        Element::new("svg")
+            .attr("version", "1.1")
+            .attr("baseProfile", "full")
+            .attr("xmlns", "http://www.w3.org/2000/svg")
+            .attr(
+                "viewBox",
+                format!("{} {} {} {}", -extent, -extent, 2. * extent, 2. * extent),
+            )
+            .children(if colour::parse(&self.theme.background) == Some(None) {
+                None
+            } else {
+                Some(
+                    Element::new("rect")
+                        .attr("x", -extent)
+                        .attr("y", -extent)
+                        .attr("width", 2. * extent)
+                        .attr("height", 2. * extent)
+                        .attr("fill", &self.theme.background),
+                )
+            })
+            .child(
+                Element::new("circle")
+                    .attr("r", layout.outer_houses)
+                    .attr("stroke", &self.theme.lines)
+                    .attr("fill", &self.theme.houses),
+            )
+            .child(
+                Element::new("circle")
+                    .attr("r", layout.inner_houses)
+                    .attr("stroke", &self.theme.lines)
+                    .attr("fill", &self.theme.above_horizon),
+            )
+            .child(self.house_cusps())
+            // Blue semicircle under horizon
+            .child(
+                Element::new("g")
+                    .attr("transform", format!("rotate({})", cycle_offset))
+                    .child(
+                        Element::new("path")
+                            .attr(
+                                "d",
+                                format!(
+                                    "M {} 0 A {r} {r} 0 0 0 {r} 0",
+                                    -layout.inner_houses,
+                                    r = layout.inner_houses
+                                ),
+                            )
+                            .attr("fill", &self.theme.below_horizon)
+                            .attr("stroke", &self.theme.lines),
+                    ),
+            )
+            .child(
+                Element::new("g")
+                    .attr(
+                        "transform",
+                        format!("rotate({})", zodiac_start + cycle_offset),
+                    )
+                    .children(degree_marks(&layout, self.theme))
+                    .child(self.zodiac_sectors())
+                    .children(self.conjunction_bands(&positions, &glyph_positions))
+                    .child(self.planet_markers(&positions, &glyph_positions))
+                    .children(if self.antiscia {
+                        Some(reflection_markers(
+                            &positions[..self.bodies()],
+                            &layout,
+                            self.theme,
+                        ))
+                    } else {
+                        None
+                    }),
+            )
+            .child(
+                Element::new("circle")
+                    .attr("r", layout.outer_houses + layout.degree_ring)
+                    .attr("stroke", &self.theme.lines)
+                    .attr("fill", "transparent"),
+            )
+            .child(
+                Element::new("g")
+                    .attr("stroke", &self.theme.zodiac)
+                    .attr("fill", "transparent")
+                    .children(if layout.outer_ring > 0. {
+                        Some(
+                            Element::new("circle")
+                                .attr("r", layout.outer_zodiac + layout.outer_ring),
+                        )
+                    } else {
+                        None
+                    })
+                    .child(Element::new("circle").attr("r", layout.outer_zodiac))
+                    .child(Element::new("circle").attr("r", layout.inner_zodiac)),
+            )
+            // Ascendant arrow
+            .child(
+                Element::new("g")
+                    .attr("transform", format!("rotate({})", cycle_offset))
+                    .attr("stroke", &self.theme.lines)
+                    .child(
+                        Element::new("path")
+                            .attr("d", self.horizon_path(-1.))
+                            .attr("stroke", &self.theme.lines),
+                    )
+                    .child(
+                        Element::new("path")
+                            .attr("d", self.arrow_path(-1.))
+                            .attr("fill", &self.theme.lines),
+                    ),
+            )
+            // Descendant arrow
+            .child(
+                Element::new("g")
+                    .attr(
+                        "transform",
+                        format!(
+                            "rotate({})",
+                            zodiac_start - self.positions.descendant() + cycle_offset
+                        ),
+                    )
+                    .attr("stroke", &self.theme.lines)
+                    .child(
+                        Element::new("path")
+                            .attr("d", self.horizon_path(1.))
+                            .attr("stroke", &self.theme.lines),
+                    )
+                    .child(
+                        Element::new("path")
+                            .attr("d", self.arrow_path(1.))
+                            .attr("stroke", &self.theme.lines)
+                            .attr("fill", &self.theme.outline),
+                    ),
+            )
+            // Centre disk
+            .child(centre)
+            .children(if self.aspect {
+                Some(self.aspects())
+            } else {
+                None
+            })
+    }
+
+    /// The horizon or meridian through the inner rings, with a tick across the degree ring on
+    /// the side of `direction`, which is -1 for the left and 1 for the right
+    fn horizon_path(&self, direction: f32) -> String {
+        let Layout {
+            inner_houses,
+            outer_houses,
+            degree_ring,
+            centre,
+            ..
+        } = self.layout;
+        format!(
+            "M {} 0 H {} M {} 0 H {} M {} 0 h {}",
+            inner_houses,
+            centre,
+            -centre,
+            -inner_houses,
+            direction * outer_houses,
+            direction * (degree_ring + 2.)
+        )
+    }
+
+    /// An arrowhead pointing out of the wheel just beyond the degree ring, on the side of
+    /// `direction`
+    fn arrow_path(&self, direction: f32) -> String {
+        format!(
+            "M {} 1 h {} l {} -1 l {} -1 h {} z",
+            direction * (self.layout.outer_houses + self.layout.degree_ring + 1.7),
+            direction * 2.,
+            direction * 3.,
+            -direction * 3.,
+            -direction * 2.
+        )
+    }
+
+    fn zodiac_start(&self) -> f32 {
+        (self.positions.ascendant() - 180.) % 360.
+    }
+
+    fn cycle_offset(&self) -> f32 {
+        30. * f32::from(self.cycle)
+    }
+
+    fn mini_horizon(&self) -> Element {
+        let zodiac_start = self.zodiac_start();
+        let sun_transform = format!("rotate({})", zodiac_start - self.positions.sun());
+        let moon_transform = format!("rotate({})", zodiac_start - self.positions.moon());
+        let horizon = format!("M {} 0 H {}", -MINI_HORIZON, MINI_HORIZON);
+        Element::new("g")
+            .attr(
+                "transform",
+                format!("scale({})", self.layout.centre / MINI_HORIZON),
+            )
+            .child(
+                Element::new("circle")
+                    .attr("r", MINI_HORIZON)
+                    .attr("stroke", &self.theme.lines)
+                    .attr("fill", &self.theme.above_horizon),
+            )
+            .child(
+                Element::new("path")
+                    .attr(
+                        "d",
+                        format!(
+                            "M {} 0 A {r} {r} 0 0 0 {r} 0",
+                            -MINI_HORIZON,
+                            r = MINI_HORIZON
+                        ),
+                    )
+                    .attr("fill", &self.theme.below_horizon)
+                    .attr("stroke", &self.theme.lines),
+            )
+            .child(
+                Element::new("path")
+                    .attr("d", horizon.as_str())
+                    .attr("stroke", &self.theme.lines),
+            )
+            .child(
+                Element::new("path")
+                    .attr(
+                        "d",
+                        format!("M {} 1 h -2 l -3 -1 l 3 -1 h 2 z", -MINI_HORIZON),
+                    )
+                    .attr("stroke", &self.theme.lines)
+                    .attr("fill", &self.theme.lines),
+            )
+            .child(
+                Element::new("g")
+                    .attr(
+                        "transform",
+                        format!("rotate({})", zodiac_start - self.positions.descendant()),
+                    )
+                    .attr("stroke", &self.theme.lines)
+                    .child(Element::new("path").attr("d", horizon.as_str()))
+                    .child(
+                        Element::new("path")
+                            .attr(
+                                "d",
+                                format!("M {} 1 h 2 l 3 -1 l -3 -1 h -2 z", MINI_HORIZON),
+                            )
+                            .attr("fill", &self.theme.outline),
+                    ),
+            )
+            .child(
+                Element::new("circle")
+                    .attr("cx", 16)
+                    .attr("r", 1.4)
+                    .attr("stroke", &self.theme.outline)
+                    .attr("fill", &self.theme.sun)
+                    .attr("transform", sun_transform),
+            )
+            .child(
+                Element::new("circle")
+                    .attr("cx", 16)
+                    .attr("r", 1.4)
+                    .attr("stroke", &self.theme.outline)
+                    .attr("fill", &self.theme.moon)
+                    .attr("transform", moon_transform),
+            )
+            .child(
+                Element::new("circle")
+                    .attr("r", 3)
+                    .attr("stroke", &self.theme.outline)
+                    .attr("fill", &self.theme.centre),
+            )
+    }
+
+    fn aspects(&self) -> Element {
+        let layout = self.layout;
+        let zodiac_start = self.zodiac_start();
+        let cycle_offset = self.cycle_offset();
+        let aspect_pairs = self
+            .positions
+            .planets_without_node()
+            .iter()
+            .take(self.planets as usize + 2)
+            .enumerate()
+            .tuple_combinations()
+            .filter_map(|((i, &a), (j, &b))| match aspect::aspect(a, b, self.orb) {
+                Some(asp) if self.aspect_types.contains(asp.aspect_type) => Some((i, j, a, b, asp)),
+                _ => None,
+            });
+        let chords =
+            aspect_pairs.map(|(i, j, a, b, aspect)| self.aspect_chord(i, j, a, b, &aspect));
+        let asc_rot = format!("rotate({})", cycle_offset);
+        let desc_rot = format!(
+            "rotate({})",
+            zodiac_start - self.positions.descendant() + cycle_offset
+        );
+        let cycle_rot = format!("rotate({})", cycle_offset);
+        Element::new("g")
+            .child(
+                Element::new("circle")
+                    .attr("r", layout.aspects)
+                    .attr("fill", &self.theme.aspect_background),
+            )
+            .child(
+                Element::new("g")
+                    .attr(
+                        "transform",
+                        format!("rotate({})", zodiac_start + cycle_offset),
+                    )
+                    .children(chords),
+            )
+            .child(
+                Element::new("circle")
+                    .attr("r", 4)
+                    .attr("fill", &self.theme.above_horizon)
+                    .attr("stroke", &self.theme.outline),
+            )
+            .child(
+                Element::new("path")
+                    .attr("d", "M -4 0 A 4 4 0 0 0 4 0")
+                    .attr("fill", &self.theme.below_horizon)
+                    .attr("stroke", &self.theme.outline)
+                    .attr("transform", cycle_rot),
+            )
+            .child(
+                Element::new("path")
+                    .attr("d", "M -4 0 H 4")
+                    .attr("stroke", &self.theme.lines)
+                    .attr("transform", desc_rot),
+            )
+            .child(
+                Element::new("path")
+                    .attr("d", "M -4 0 H 4")
+                    .attr("stroke", &self.theme.lines)
+                    .attr("transform", asc_rot),
+            )
+            .child(
+                Element::new("circle")
+                    .attr("r", "1.2")
+                    .attr("fill", &self.theme.outline)
+                    .attr("stroke", &self.theme.centre),
+            )
+    }
+
+    /// The line of an aspect between bodies `i` and `j` at `a` and `b`, with its details on
+    /// hovering
+    fn aspect_chord(&self, i: usize, j: usize, a: f32, b: f32, aspect: &aspect::Aspect) -> Element {
+        let layout = self.layout;
+        let width = 0.5 + 1.2 * aspect.close;
+        let line = match aspect.aspect_type {
+            aspect::Type::Zero => conjunction_arc(layout.aspects, a, b, width, self.theme),
+            aspect::Type::Thirty => Element::new("path")
+                .attr("d", chord_path(layout.aspects, a, b))
+                .attr("stroke", &self.theme.soft_aspects)
+                .attr("stroke-width", width)
+                .attr("stroke-dasharray", "2 1"),
+            aspect_type => {
+                let stroke = match aspect_type {
+                    aspect::Type::Ninety | aspect::Type::OneEighty => &self.theme.hard_aspects,
+                    _ => &self.theme.soft_aspects,
+                };
+                Element::new("path")
+                    .attr("d", chord_path(layout.aspects, a, b))
+                    .attr("stroke", stroke)
+                    .attr("stroke-width", width)
+            }
+        };
+        // The lines are thin, so a wider invisible one takes the pointer
+        let hit_path = match aspect.aspect_type {
+            aspect::Type::Zero => arc_path(layout.aspects - 1.5, a, b),
+            _ => chord_path(layout.aspects, a, b),
+        };
+        let hit_area = Element::new("path")
+            .attr("d", hit_path)
+            .attr("fill", "none")
+            .attr("stroke", "transparent")
+            .attr("stroke-width", 3);
+        let tooltip = format!(
+            "{} {} {}, orb {}",
+            NAMES[i],
+            aspect.aspect_type.name().to_lowercase(),
+            NAMES[j],
+            arc(aspect.orb)
+        );
+        dimmed(
+            self.hoverable(Element::new("g").child(line), hit_area, tooltip),
+            !self.in_focus(&[i, j]),
+        )
+    }
+
+    /// Tinted bands in the house ring linking the glyphs of conjoined bodies
+    fn conjunction_bands(&self, positions: &[f32], glyph_positions: &[f32]) -> Option<Element> {
+        if !self.aspect || !self.aspect_types.contains(aspect::Type::Zero) {
+            return None;
+        }
+        let layout = self.layout;
+        // The Node and the lots take no part in aspects
+        let bands = (0..self.bodies().min(10))
+            .tuple_combinations()
+            .filter(|&(i, j)| {
+                matches!(
+                    aspect::aspect(positions[i], positions[j], self.orb),
+                    Some(aspect::Aspect {
+                        aspect_type: aspect::Type::Zero,
+                        ..
+                    })
+                )
+            })
+            .map(|(i, j)| {
+                let band = Element::new("path")
+                    .attr(
+                        "d",
+                        band_path(
+                            layout.inner_houses,
+                            layout.outer_houses,
+                            glyph_positions[i],
+                            glyph_positions[j],
+                        ),
+                    )
+                    .attr("fill", &self.theme.conjunction_bands)
+                    .attr("fill-opacity", "0.6");
+                dimmed(band, !self.in_focus(&[i, j]))
+            });
+        Some(Element::new("g").children(bands))
+    }
+
+    /// The zodiac start and cycle offset are used to flip the glyphs right side up
+    fn zodiac_sectors(&self) -> Element {
+        let zodiac_start = self.zodiac_start();
+        let cycle_offset = self.cycle_offset();
+        let layout = self.layout;
+        let sector = |offset| {
+            let angle = (30 * offset) as f32;
+            let rotation = format!("rotate({})", -angle);
+            let glyph = layout.sign_glyphs.map(|radius| {
+                let text_trans = format!(
+                    "rotate(-15) translate({}, 0) rotate({}) scale(0.8)",
+                    radius,
+                    angle + 15. - zodiac_start - cycle_offset
+                );
+                self.glyph(
+                    ZODIAC_GLYPHS[offset],
+                    Some(glyphs::ZODIAC[offset]),
+                    &self.theme.zodiac,
+                    text_trans,
+                )
+            });
+            Element::new("g")
+                .attr("transform", rotation)
+                .child(
+                    Element::new("path")
+                        .attr(
+                            "d",
+                            format!("M {} 0 H {}", layout.inner_zodiac, layout.outer_zodiac),
+                        )
+                        .attr("stroke", &self.theme.zodiac),
+                )
+                .children(glyph)
+        };
+        Element::new("g").children((0..12).map(sector))
+    }
+
+    /// The degrees and minutes of `position` in its sign, like `22♑14`, written along the
+    /// radius at `delta` from the position, where the label was placed. The label is turned
+    /// over on the left of the wheel so that it never reads upside down.
+    fn position_label(&self, radius: f32, position: f32, delta: f32) -> Element {
+        let (sign, degrees, minutes) = app::sign_degrees_minutes(position);
+        let turn = self.zodiac_start() + self.cycle_offset() - position - delta;
+        let flip = if turn.to_radians().cos() < 0. {
+            180.
+        } else {
+            0.
+        };
+        // The numbers stand a little away from either side of the sign glyph in the middle
+        let scale = LABEL_SIZE / GLYPH_SIZE;
+        let edge = glyphs::HALF_WIDTH * glyphs::FONT_SCALE * scale + 0.15 * LABEL_SIZE;
+        let number = |x: f32, anchor: &str, text: String| match self.glyph_style {
+            GlyphStyle::Outline => Self::lettering(
+                &text,
+                LABEL_SIZE,
+                anchor,
+                &self.theme.bodies,
+                &format!("translate({}, 0)", x),
+            ),
+            GlyphStyle::Font => Element::new("text")
+                .attr("x", x)
+                .attr("font-size", LABEL_SIZE)
+                .attr("text-anchor", anchor)
+                .attr("dominant-baseline", "central")
+                .attr("fill", &self.theme.bodies)
+                .text(text),
+        };
+        Element::new("g")
+            .attr(
+                "transform",
+                format!(
+                    "rotate({}) translate({}, 0) rotate({})",
+                    -delta, radius, flip
+                ),
+            )
+            .child(number(-edge, "end", degrees.to_string()))
+            .child(self.glyph(
+                ZODIAC_GLYPHS[sign],
+                Some(glyphs::ZODIAC[sign]),
+                &self.theme.bodies,
+                format!("scale({})", scale),
+            ))
+            .child(number(edge, "start", format!("{:02}", minutes)))
+    }
+
+    /// A glyph in the chosen style, placed by `transform` as text would be, that is centred on
+    /// the origin. Labels without an outline of their own are spelt out in letter outlines.
+    fn glyph(
+        &self,
+        text: impl Display,
+        outline: Option<&str>,
+        colour: &str,
+        transform: String,
+    ) -> Element {
+        match (self.glyph_style, outline) {
+            (GlyphStyle::Outline, Some(outline)) => Element::new("path")
+                .attr("d", outline)
+                .attr("fill", "none")
+                .attr("stroke", colour)
+                .attr("stroke-width", 0.8)
+                .attr("stroke-linecap", "round")
+                .attr(
+                    "transform",
+                    format!(
+                        "{} scale({}) translate({}, {})",
+                        transform,
+                        glyphs::FONT_SCALE,
+                        -glyphs::HALF_WIDTH,
+                        glyphs::HALF_WIDTH
+                    ),
+                ),
+            (GlyphStyle::Outline, None) => {
+                Self::lettering(&text.to_string(), GLYPH_SIZE, "middle", colour, &transform)
+            }
+            (GlyphStyle::Font, _) => Element::new("text")
+                .attr("fill", colour)
+                .attr("transform", transform)
+                .text(text),
+        }
+    }
+
+    /// `text` in letter outlines at font size `size`, aligned on the origin by `anchor` and
+    /// centred vertically like text with a central baseline
+    fn lettering(text: &str, size: f32, anchor: &str, colour: &str, transform: &str) -> Element {
+        let (letters, width) = glyphs::letters(text);
+        let start = match anchor {
+            "start" => 0.,
+            "end" => -width,
+            _ => -width / 2.,
+        };
+        let letters = letters.into_iter().map(|(x, outline)| {
+            Element::new("path")
+                .attr("d", outline)
+                .attr("transform", format!("translate({}, 0)", start + x))
+        });
+        Element::new("g")
+            .attr("fill", "none")
+            .attr("stroke", colour)
+            .attr("stroke-width", 0.8)
+            .attr("stroke-linecap", "round")
+            .attr(
+                "transform",
+                format!(
+                    "{} scale({}) translate(0, 5)",
+                    transform,
+                    size * glyphs::LETTER_SCALE
+                ),
+            )
+            .children(letters)
+    }
+
+    /// The daily motion of body `i`, if it is known
+    fn speed(&self, i: usize) -> Option<f32> {
+        self.speeds.get(i).copied().flatten()
+    }
+
+    /// The number of displayed bodies, which come before the lots in the marker positions
+    fn bodies(&self) -> usize {
+        (self.planets as usize + 2).min(11)
+    }
+
+    /// The positions of the displayed planets and lots, along with the places found for their
+    /// glyphs, followed by the places for their position labels if those are written
+    fn marker_positions(&self) -> (Vec<f32>, Vec<f32>) {
+        let layout = self.layout;
+        let slot = |size: f32| placement::width(size, layout.glyphs);
+        let bodies = self.positions.planets()[..self.bodies()]
+            .iter()
+            .map(|&target| Label {
+                target,
+                width: slot(GLYPH_SIZE * layout.glyph_scale),
+                track: 0,
+            });
+        // Lots are written at half size, a little over half a glyph for each letter
+        let lots = self.lots.iter().map(|lot| Label {
+            target: lot.position,
+            width: slot(0.5 * GLYPH_SIZE * (0.6 * lot.symbol.chars().count() as f32).max(1.)),
+            track: 0,
+        });
+        let mut labels: Vec<Label> = bodies.chain(lots).collect();
+        let positions: Vec<f32> = labels.iter().map(|label| label.target).collect();
+        // The position labels have a ring of their own, across which each is as wide as its
+        // font is high
+        if let Some(radius) = layout.position_labels {
+            labels.extend(positions.iter().map(|&target| Label {
+                target,
+                width: placement::width(1.5 * LABEL_SIZE, radius),
+                track: 1,
+            }));
+        }
+        (positions, placement::place(&labels))
+    }
+
+    fn planet_markers(&self, positions: &[f32], glyph_positions: &[f32]) -> Element {
+        let zodiac_start = self.zodiac_start();
+        let cycle_offset = self.cycle_offset();
+        let bodies = self.bodies();
+        let marker = |(i, a): (usize, &f32)| {
+            let delta = glyph_positions[i] - a;
+            // Lots are labelled with a few letters, which need to be smaller
+            let (glyph, outline, scale) = if i < bodies {
+                (
+                    PLANET_GLYPHS[i].to_string(),
+                    Some(glyphs::BODIES[i]),
+                    self.layout.glyph_scale,
+                )
+            } else {
+                (self.lots[i - bodies].symbol.clone(), None, 0.5)
+            };
+            let text_trans = format!(
+                "rotate({}) translate({}, 0) rotate({}) scale({})",
+                -delta,
+                self.layout.glyphs,
+                a + delta - zodiac_start - cycle_offset,
+                scale
+            );
+            // Written small at the foot of the glyph, like a subscript
+            let retrograde = if i < bodies && matches!(self.speed(i), Some(speed) if speed < 0.) {
+                Some(self.glyph(
+                    glyphs::RETROGRADE_CHARACTER,
+                    Some(glyphs::RETROGRADE),
+                    &self.theme.bodies,
+                    format!("{} translate(11, 2) scale(0.45)", text_trans),
+                ))
+            } else {
+                None
+            };
+            // The glyph alone is hard to point at, so a disk behind it takes the pointer
+            let hit_area = Element::new("circle")
+                .attr("r", GLYPH_SIZE * scale / 2.)
+                .attr("fill", "transparent")
+                .attr("stroke", "none")
+                .attr(
+                    "transform",
+                    format!("rotate({}) translate({}, 0)", -delta, self.layout.glyphs),
+                );
+            let marker = Element::new("g")
+                .attr("transform", format!("rotate({})", -a))
+                .attr("stroke-width", "0.5");
+            // Clicks on the wheel are traced back to the body by this mark
+            let marker = if self.interactive {
+                marker.attr("data-body", i)
+            } else {
+                marker
+            };
+            let marker = self
+                .hoverable(marker, hit_area, self.body_tooltip(i, *a))
+                .child(
+                    Element::new("circle")
+                        .attr("cx", self.layout.inner_markers)
+                        .attr("r", "1.2")
+                        .attr("stroke", &self.theme.markers)
+                        .attr("fill", "transparent"),
+                )
+                .child(
+                    Element::new("circle")
+                        .attr("cx", self.layout.markers)
+                        .attr("r", "1.5")
+                        .attr("fill", &self.theme.markers),
+                )
+                .child(self.glyph(&glyph, outline, &self.theme.bodies, text_trans.clone()))
+                .children(retrograde)
+                .children(self.layout.position_labels.map(|radius| {
+                    let label_delta = glyph_positions[positions.len() + i] - a;
+                    self.position_label(radius, *a, label_delta)
+                }));
+            dimmed(marker, !self.in_focus(&[i]))
+        };
+        Element::new("g").children(positions.iter().enumerate().map(marker))
+    }
+
+    /// The name, position, house and daily motion of a body or lot
+    fn body_tooltip(&self, i: usize, position: f32) -> String {
+        let bodies = self.bodies();
+        let name = if i < bodies {
+            NAMES[i]
+        } else {
+            &self.lots[i - bodies].name
+        };
+        let motion = match self.speed(i).filter(|_| i < bodies) {
+            Some(speed) if speed < 0. => format!("\n{} a day, retrograde", arc(speed)),
+            Some(speed) => format!("\n{} a day", arc(speed)),
+            None => String::new(),
+        };
+        format!(
+            "{}\n{}\nHouse {}{}",
+            name,
+            truncate_angle(position.rem_euclid(360.)),
+            self.house(position),
+            motion
+        )
+    }
+
+    /// The house `position` falls in, counted from the ascendant in equal houses
+    fn house(&self, position: f32) -> u16 {
+        ((position - self.positions.ascendant()).rem_euclid(360.) / 30.) as u16 % 12 + 1
+    }
+
+    /// Whether any of `bodies` has the focus, which they all do when nothing has
+    fn in_focus(&self, bodies: &[usize]) -> bool {
+        match self.focus {
+            Some(focus) => bodies.contains(&focus),
+            None => true,
+        }
+    }
+
+    /// `element` with `tooltip` on hovering over it or `hit_area`, if the wheel is interactive
+    fn hoverable(&self, element: Element, hit_area: Element, tooltip: String) -> Element {
+        if self.interactive {
+            element
+                .child(hit_area)
+                .child(Element::new("title").text(tooltip))
+        } else {
+            element
+        }
+    }
+
+    /// The house cusps, each with its position on hovering. The cusps are fixed on the screen
+    /// while the ascendant turns with the cycle, so the cusp drawn at `offset` twelfths round
+    /// from the descendant is found from the cycle.
+    fn house_cusps(&self) -> Element {
+        let layout = self.layout;
+        let path = format!("M {} 0 H {}", layout.inner_houses, layout.outer_houses);
+        let cusp = |offset: u16| {
+            let house = (6 + self.cycle % 12 + 12 - offset) % 12 + 1;
+            let position = self.positions.ascendant() + 30. * f32::from(house - 1);
+            let tooltip = format!(
+                "Cusp of house {}\n{}",
+                house,
+                truncate_angle(position.rem_euclid(360.))
+            );
+            let hit_area = Element::new("path")
+                .attr("d", &path)
+                .attr("stroke", "transparent")
+                .attr("stroke-width", 3);
+            self.hoverable(
+                Element::new("g")
+                    .attr("transform", format!("rotate({})", 30 * offset))
+                    .child(Element::new("path").attr("d", &path)),
+                hit_area,
+                tooltip,
+            )
+        };
+        Element::new("g")
+            .attr("stroke", &self.theme.lines)
+            .children((0..12).map(cusp))
+    }
+}
+
+/// `element` faded into the background if `faded`
+fn dimmed(element: Element, faded: bool) -> Element {
+    if faded {
+        element.attr("opacity", DIMMED)
+    } else {
+        element
+    }
+}
+
+/// An angle as whole degrees and minutes, like `1° 01'`, keeping its sign
+fn arc(degrees: f32) -> String {
+    let minutes = (degrees.abs() * 60.).round() as u32;
+    let sign = if degrees < 0. && minutes > 0 { "-" } else { "" };
+    format!("{}{}° {:02}'", sign, minutes / 60, minutes % 60)
+}
+
+/// Hollow markers on the zodiac ring, in one colour for antiscia and another for
+/// contra-antiscia
+fn reflection_markers(positions: &[f32], layout: &Layout, theme: &Theme) -> Element {
+    let marker = |(i, &position): (usize, &f32), reflection: Reflection| {
+        let stroke = match reflection {
+            Reflection::Antiscion => &theme.antiscia,
+            Reflection::ContraAntiscion => &theme.contra_antiscia,
+        };
+        Element::new("circle")
+            .attr("cx", layout.markers)
+            .attr("r", "1.5")
+            .attr("stroke", stroke)
+            .attr("stroke-width", "0.5")
+            .attr("fill", "transparent")
+            .attr(
+                "transform",
+                format!("rotate({})", -reflection.reflect(position)),
+            )
+            .child(Element::new("title").text(format!(
+                "{} {}",
+                PLANET_GLYPHS[i],
+                reflection.name()
+            )))
+    };
+    Element::new("g").children(
+        Reflection::ALL
+            .iter()
+            .flat_map(|&reflection| {
+                positions
+                    .iter()
+                    .enumerate()
+                    .map(move |p| marker(p, reflection))
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Conjunctions have no chord, so they are drawn as an arc along the aspect circle
+/// between the two bodies, with a marker at each end
+fn conjunction_arc(aspects: f32, a: f32, b: f32, width: f32, theme: &Theme) -> Element {
+    let radius = aspects - 1.5;
+    let marker = |angle: f32| {
+        Element::new("circle")
+            .attr("cx", radius)
+            .attr("r", 1)
+            .attr("fill", &theme.conjunctions)
+            .attr("transform", format!("rotate({})", -angle))
+    };
+    Element::new("g")
+        .child(
+            Element::new("path")
+                .attr("d", arc_path(radius, a, b))
+                .attr("stroke", &theme.conjunctions)
+                .attr("stroke-width", width)
+                .attr("fill", "transparent"),
+        )
+        .child(marker(a))
+        .child(marker(b))
+}
+
+/// Marks across the degree ring every `degree_step` degrees. With a mark for every degree,
+/// the fives and tens are drawn longer so they can be counted.
+fn degree_marks(layout: &Layout, theme: &Theme) -> Option<Element> {
+    if layout.degree_step == 0 {
+        return None;
+    }
+    let step = layout.degree_step;
+    let marks = (0..360).step_by(step as usize).map(|degree: u16| {
+        let length = if step >= 5 || degree % 10 == 0 {
+            1.
+        } else if degree % 5 == 0 {
+            0.7
+        } else {
+            0.4
+        };
+        Element::new("path")
+            .attr(
+                "d",
+                format!(
+                    "M {} 0 h {}",
+                    layout.outer_houses,
+                    length * layout.degree_ring
+                ),
+            )
+            .attr("transform", format!("rotate({})", degree))
+    });
+    Some(
+        Element::new("g")
+            .attr("stroke", &theme.lines)
+            .children(marks),
+    )
+}
+
+/// Order two angles so that going counter-clockwise from the first reaches the second
+/// the short way round
+fn short_way(a: f32, b: f32) -> (f32, f32) {
+    if (b - a).rem_euclid(360.) <= 180. {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn arc_path(radius: f32, a: f32, b: f32) -> String {
+    let (start, end) = short_way(a, b);
+    let x0 = radius * start.to_radians().cos();
+    let y0 = -radius * start.to_radians().sin();
+    let x1 = radius * end.to_radians().cos();
+    let y1 = -radius * end.to_radians().sin();
+    format!("M {} {} A {r} {r} 0 0 0 {} {}", x0, y0, x1, y1, r = radius)
+}
+
+/// An annular sector between two radii, padded by a few degrees on either side
+fn band_path(inner: f32, outer: f32, a: f32, b: f32) -> String {
+    const PADDING: f32 = 3.;
+    let (start, end) = short_way(a, b);
+    let (start, end) = (start - PADDING, end + PADDING);
+    let point = |radius: f32, angle: f32| {
+        (
+            radius * angle.to_radians().cos(),
+            -radius * angle.to_radians().sin(),
+        )
+    };
+    let (x0, y0) = point(outer, start);
+    let (x1, y1) = point(outer, end);
+    let (x2, y2) = point(inner, end);
+    let (x3, y3) = point(inner, start);
+    format!(
+        "M {} {} A {o} {o} 0 0 0 {} {} L {} {} A {i} {i} 0 0 1 {} {} Z",
+        x0,
+        y0,
+        x1,
+        y1,
+        x2,
+        y2,
+        x3,
+        y3,
+        o = outer,
+        i = inner
+    )
+}
+
+fn chord_path(radius: f32, start: f32, end: f32) -> String {
+    let x0 = radius * start.to_radians().cos();
+    let y0 = -radius * start.to_radians().sin();
+    let x1 = radius * end.to_radians().cos();
+    let y1 = -radius * end.to_radians().sin();
+    format!("M {} {} L {} {}", x0, y0, x1, y1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+    use crate::lots::{self, Lot, Operand};
+
+    /// The names of the elements in `svg`, checking on the way that each one is closed
+    fn elements(svg: &str) -> Vec<&str> {
+        let mut open = Vec::new();
+        let mut names = Vec::new();
+        for tag in svg.split('<').skip(1) {
+            let tag = &tag[..tag.find('>').expect("unterminated tag")];
+            if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(open.pop(), Some(name), "mismatched </{}>", name);
+            } else {
+                let name = tag.split(' ').next().unwrap_or(tag).trim_end_matches('/');
+                names.push(name);
+                if !tag.ends_with('/') {
+                    open.push(name);
+                }
+            }
+        }
+        assert!(open.is_empty(), "unclosed {:?}", open);
+        names
+    }
+
+    #[test]
+    fn renders_the_sample_chart() {
+        let (positions, speeds) = input::parse_zet9(input::SAMPLE).unwrap();
+        let mut lots = lots::standard();
+        lots.push(Lot::new(
+            "Τύχη",
+            Operand::Point(0),
+            Operand::Point(1),
+            false,
+        ));
+        let lots = lots::positions(&lots, &positions);
+        let theme = Theme::default();
+        let wheel = Wheel {
+            positions: &positions,
+            cycle: 0,
+            aspect: true,
+            aspect_types: Default::default(),
+            orb: 8.,
+            planets: 9,
+            layout: Layout::default().with_position_labels(),
+            antiscia: false,
+            lots: &lots,
+            speeds: &speeds,
+            glyph_style: GlyphStyle::Outline,
+            theme: &theme,
+            focus: None,
+            interactive: false,
+        };
+        let svg = wheel.to_svg();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(r#"viewBox="-105 -105 210 210""#));
+        assert!(!svg.contains("NaN"));
+
+        let names = elements(&svg);
+        assert_eq!(names.iter().filter(|&&name| name == "svg").count(), 1);
+        assert!(
+            !names.contains(&"text"),
+            "outlined glyphs and labels need no fonts"
+        );
+        assert!(
+            !svg.contains("data-body"),
+            "only the page needs to know what was clicked"
+        );
+        for outline in glyphs::ZODIAC.iter().chain(&glyphs::BODIES) {
+            assert!(svg.contains(outline), "a glyph is missing");
+        }
+        assert_eq!(svg, wheel.to_svg(), "rendering is not repeatable");
+
+        let page = Wheel {
+            interactive: true,
+            ..wheel
+        }
+        .to_svg();
+        for marker in 0..11 + lots.len() {
+            assert!(page.contains(&format!(r#"data-body="{}""#, marker)));
+        }
+        assert!(elements(&page).contains(&"title"));
+    }
+}